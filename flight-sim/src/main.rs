@@ -1,284 +1,6798 @@
+use gilrs::{Axis, Button, Gamepad, Gilrs};
+use macroquad::audio::{
+    PlaySoundParams, Sound, load_sound_from_bytes, play_sound, set_sound_volume,
+};
 use macroquad::prelude::*;
+use std::collections::{HashSet, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
 
+const ATMOSPHERE_SCALE_HEIGHT: f32 = 8500.0;
 const GRAVITY: Vec3 = Vec3::from_array([0.0, -9.81, 0.0]);
-const DRAG_COEFF: f32 = 0.08;
-const LIFT_COEFF: f32 = 11.5;
-const THROTTLE_STEP: f32 = 0.5;
-const MAX_SPEED: f32 = 130.0;
-const MIN_SPEED: f32 = 12.0;
-const ROLL_RATE: f32 = 1.4;
-const PITCH_RATE: f32 = 0.9;
-const YAW_RATE: f32 = 0.4;
+const MOON_GRAVITY: Vec3 = Vec3::from_array([0.0, -1.62, 0.0]);
+const MS_TO_KMH: f32 = 3.6;
+const MS_TO_KNOTS: f32 = 1.943_844;
+const METERS_TO_FEET: f32 = 3.280_84;
+const STALL_WARNING_AOA: f32 = 12.0;
+const CRITICAL_AOA: f32 = 16.0;
+const STALL_LIFT_FACTOR: f32 = 0.15;
+const STALL_BUFFET: f32 = 6.0;
+const CONTROL_STALL_AUTHORITY: f32 = 0.1;
+const CONTROL_MIN_AUTHORITY: f32 = 0.25;
+const CONTROL_MAX_AUTHORITY: f32 = 1.8;
+const YAW_ROLL_COUPLING: f32 = 0.5;
+const SIDESLIP_DRAG: f32 = 0.012;
+const MAX_FUEL: f32 = 100.0;
+const REFERENCE_MASS: f32 = 1000.0;
+const FUEL_BURN_RATE: f32 = 0.4;
+const ENGINE_SPOOL_TIME: f32 = 1.5;
+const ENGINE_RATED_RPM: f32 = 2400.0;
+const AFTERBURNER_SPEED: f32 = 1.35;
+const AFTERBURNER_THRUST: f32 = 1.6;
+const AFTERBURNER_FUEL: f32 = 3.0;
+const AFTERBURNER_MAX_TIME: f32 = 6.0;
+const AFTERBURNER_COOLDOWN: f32 = 10.0;
+const TRIM_RATE: f32 = 0.25;
+const MAX_PITCH_TRIM: f32 = 0.3;
+const MAX_ROLL_TRIM: f32 = 0.2;
+const MAX_YAW_TRIM: f32 = 0.3;
+const AP_KP: f32 = 0.8;
+const AP_KI: f32 = 0.15;
+const AP_KD: f32 = 2.0;
+const AT_KP: f32 = 0.25;
+const AT_SETPOINT_RATE: f32 = 5.0;
+const AP_MAX_PITCH: f32 = 12.0;
+const G_LIMIT: f32 = 9.0;
+const OVER_G_BREAKUP_TIME: f32 = 1.5;
+const GLOC_ONSET: f32 = 5.0;
+const GLOC_BUILD_RATE: f32 = 0.12;
+const REDOUT_ONSET: f32 = -2.5;
+const REDOUT_BUILD_RATE: f32 = 0.25;
+const VISION_RECOVERY_RATE: f32 = 0.35;
+const VIGNETTE_BANDS: usize = 12;
+const WHEEL_BRAKE_DECELERATION: f32 = 12.0;
+const ROLLING_RESISTANCE: f32 = 0.03;
+const MAX_ROLLING_RESISTANCE: f32 = 1.0;
+const TAXI_MAX_SPEED: f32 = 20.0;
+const IDLE_THROTTLE: f32 = 0.1;
+const TIRE_GRIP: f32 = 8.0;
+const NOSEWHEEL_MAX_ANGLE: f32 = 35.0;
+const WHEELBASE: f32 = 4.0;
+const AIRBRAKE_DRAG: f32 = 0.03;
+const GEAR_TRANSIT_TIME: f32 = 3.0;
+const GEAR_DRAG: f32 = 0.02;
+const GEAR_LENGTH: f32 = 2.0;
+const FLAP_STEP: f32 = 0.25;
+const FLAP_LIFT: f32 = 0.6;
+const FLAP_DRAG: f32 = 0.03;
+const FLAP_LIMIT_SPEED_RATIO: f32 = 0.6;
+const GROUND_CLEARANCE: f32 = 2.5;
+const GROUND_EFFECT_CEILING: f32 = 14.0;
+const GROUND_EFFECT_MAX: f32 = 1.4;
+const CRASH_SINK_RATE: f32 = 6.0;
+const CRASH_ATTITUDE: f32 = 30.0;
+const LANDING_MAX_SLOPE: f32 = 12.0;
+const GPWS_WARNING_TIME: f32 = 8.0;
+const GPWS_MAX_HEIGHT: f32 = 600.0;
+const GPWS_MIN_SINK: f32 = 5.0;
+const MAX_GPWS_WARNING_TIME: f32 = 30.0;
+const RESPAWN_DELAY: f32 = 3.0;
+const TERRAIN_CELLS: usize = 96;
+const TERRAIN_CHUNK_CELLS: usize = 16;
+const TERRAIN_FLAT_BLEND: f32 = 300.0;
+const HORIZON_CLOUD_COUNT: usize = 48;
+const HORIZON_CLOUD_ALTITUDE: f32 = 1500.0;
+const HORIZON_CLOUD_MIN_RADIUS: f32 = 4500.0;
+const HORIZON_CLOUD_RADIUS_SPREAD: f32 = 1500.0;
+const HORIZON_CLOUD_SEGMENTS: usize = 12;
+const HORIZON_CLOUD_ALPHA: f32 = 0.45;
+const HORIZON_CLOUD_FADE_START: f32 = 15.0;
+const HORIZON_CLOUD_FADE_END: f32 = 45.0;
+const ALTITUDE_MARKER_STEP: f32 = 500.0;
+const ALTITUDE_MARKER_LEVELS: usize = 6;
+const ALTITUDE_GRID_EXTENT: f32 = 1200.0;
+const ALTITUDE_GRID_SPACING: f32 = 200.0;
+const ALTITUDE_MARKER_FADE: f32 = 1500.0;
+const SHADOW_FADE_HEIGHT: f32 = 250.0;
+const SKY_RADIUS: f32 = 6000.0;
+const SKY_RINGS: usize = 10;
+const SKY_SEGMENTS: usize = 32;
+const SUN_TILT: f32 = 35.0;
+const SUN_SIZE: f32 = 160.0;
+const FOG_DENSITY: f32 = 0.0006;
+const RUNWAY_CENTER: Vec2 = Vec2::from_array([0.0, -900.0]);
+const RUNWAY_LENGTH: f32 = 700.0;
+const RUNWAY_WIDTH: f32 = 40.0;
+const GLIDESLOPE_ANGLE: f32 = 3.0;
+const GLIDESLOPE_FULL_SCALE: f32 = 0.7;
+const LOCALIZER_FULL_SCALE: f32 = 2.5;
+const LOCALIZER_COVERAGE: f32 = 35.0;
+const ILS_RANGE: f32 = 5000.0;
+const ILS_AIM_DISTANCE: f32 = 120.0;
+const APPROACH_DISTANCE: f32 = 2400.0;
+const APPROACH_SPEED: f32 = 45.0;
+const TAKEOFF_START_INSET: f32 = 40.0;
+const ROTATE_SPEED_RATIO: f32 = 2.0;
+const COACH_CLIMB_HEIGHT: f32 = 60.0;
+const COACH_DONE_TIME: f32 = 3.0;
+const AEROBATIC_MIN_RATE: f32 = 20.0;
+const AEROBATIC_TIMEOUT: f32 = 1.5;
+const AEROBATIC_LOOP_PITCH: f32 = 70.0;
+const AEROBATIC_BANNER_TIME: f32 = 1.5;
+const ROLL_POINTS: u32 = 100;
+const LOOP_POINTS: u32 = 150;
+const LANDING_MAX_SINK: f32 = 3.0;
+const LANDING_MAX_SPEED: f32 = 45.0;
+const LANDING_MAX_ATTITUDE: f32 = 10.0;
+const LANDING_MAX_CRAB: f32 = 15.0;
+const RTB_ALTITUDE: f32 = 250.0;
+const RTB_ARRIVAL_RADIUS: f32 = 300.0;
+const RTB_MAX_BANK: f32 = 30.0;
+const RECOVER_PITCH: f32 = 5.0;
+const RECOVER_RATE: f32 = 1.5;
+const SPIN_ENTRY_YAW: f32 = 0.5;
+const SPIN_ENTRY_SIDESLIP: f32 = 15.0;
+const SPIN_YAW_RATE: f32 = 2.2;
+const SPIN_PITCH: f32 = -55.0;
+const SPIN_PITCH_RATE: f32 = 2.0;
+const SPIN_SINK_RATE: f32 = 35.0;
+const SPIN_CAPTURE_RATE: f32 = 1.2;
+const SPIN_RECOVERY_RUDDER: f32 = 0.5;
+const SPIN_STICK_CENTER: f32 = 0.2;
+const SPIN_RECOVERY_TIME: f32 = 1.5;
+const SPIN_REENTRY_DELAY: f32 = 3.0;
+const REALISTIC_STALL_LIFT_FACTOR: f32 = 0.04;
+const REALISTIC_STALL_NOSE_DROP: f32 = 0.6;
+const REALISTIC_CLIMB_BLEED: f32 = 0.6;
+const REALISTIC_G_BLEED: f32 = 0.05;
+const CROSSWIND: Vec3 = Vec3::from_array([9.0, 0.0, 0.0]);
+const DEFAULT_FOV: f32 = 65.0;
+const ZOOM_FOV: f32 = 20.0;
+const CAMERA_STIFFNESS: f32 = 6.0;
+const FOV_LERP_RATE: f32 = 8.0;
+const TOWER_POSITION: Vec3 = Vec3::from_array([70.0, 26.0, -700.0]);
+const ORBIT_SENSITIVITY: f32 = 3.0;
+const ORBIT_MIN_DISTANCE: f32 = 12.0;
+const ORBIT_MAX_DISTANCE: f32 = 300.0;
+const CHASE_DISTANCE: f32 = 32.0;
+const CHASE_HEIGHT: f32 = 14.0;
+const CHASE_MIN_DISTANCE: f32 = 12.0;
+const CHASE_MAX_DISTANCE: f32 = 120.0;
+const CHASE_MIN_HEIGHT: f32 = 2.0;
+const CHASE_MAX_HEIGHT: f32 = 40.0;
+const CHASE_HEIGHT_RATE: f32 = 12.0;
+const CAMERA_SHAKE_SPEED: f32 = 0.04;
+const CAMERA_SHAKE_TURBULENCE: f32 = 0.08;
+const CAMERA_COCKPIT_SHAKE: f32 = 2.0;
+const CAMERA_TOUCHDOWN_JOLT: f32 = 0.25;
+const CAMERA_OVERSTRESS_RATIO: f32 = 0.8;
+const CAMERA_OVERSTRESS_JOLT: f32 = 0.2;
+const CAMERA_JOLT_DECAY: f32 = 6.0;
+const SURFACE_MAX_DEFLECTION: f32 = 25.0;
+const WINGTIP_OFFSET: f32 = 7.0;
+const PROPELLER_RADIUS: f32 = 1.6;
+const PROPELLER_SPIN_RATE: f32 = 45.0;
+const PROPELLER_DISC_ALPHA: f32 = 0.45;
+const EXHAUST_LENGTH: f32 = 3.0;
+const EXHAUST_RADIUS: f32 = 0.45;
+const EXHAUST_PULSE_RATE: f32 = 30.0;
+const ENGINE_VISUAL_SEGMENTS: usize = 16;
+const TRAIL_LIFETIME: f32 = 2.5;
+const MAX_TRAIL_PARTICLES: usize = 600;
+const MOTION_BLUR_GHOSTS: usize = 4;
+const MOTION_BLUR_ALPHA: f32 = 0.35;
+const MOTION_BLUR_MIN_SPEED: f32 = 40.0;
+const MOTION_BLUR_FULL_SPEED: f32 = 120.0;
+const MOTION_BLUR_MAX_STEP: f32 = 50.0;
+const VORTEX_THRESHOLD: f32 = 0.25;
+const VORTEX_LIFETIME: f32 = 1.6;
+const VORTEX_SPIN: f32 = 9.0;
+const VORTEX_RADIUS: f32 = 0.8;
+const MAX_VORTEX_PARTICLES: usize = 400;
+const EXPLOSION_LIFETIME: f32 = 2.5;
+const EXPLOSION_FLASH_TIME: f32 = 0.4;
+const EXPLOSION_FLASH_RADIUS: f32 = 14.0;
+const EXPLOSION_SMOKE_RADIUS: f32 = 22.0;
+const DEBRIS_COUNT: usize = 40;
+const DEBRIS_SPEED: f32 = 25.0;
+const DEBRIS_CARRY: f32 = 0.3;
+const DEBRIS_SPIN: f32 = 8.0;
+const PROJECTILE_SPEED: f32 = 400.0;
+const PROJECTILE_LIFETIME: f32 = 3.0;
+const PROJECTILE_MUZZLE_OFFSET: f32 = 6.0;
+const PROJECTILE_RADIUS: f32 = 0.6;
+const PROJECTILE_STREAK: f32 = 6.0;
+const MAX_PROJECTILES: usize = 64;
+const FORCE_VECTOR_SCALE: f32 = 0.6;
+const VELOCITY_VECTOR_SCALE: f32 = 0.25;
+const DEFAULT_WIND: Vec3 = Vec3::from_array([4.0, 0.0, 1.5]);
+const MAX_WIND: f32 = 40.0;
+const TURBULENCE_STRENGTH: f32 = 3.5;
+const TURBULENCE_JITTER: f32 = 0.08;
+const DAY_LENGTH: f32 = 1200.0;
+const TIME_SCRUB_RATE: f32 = 3.0;
+const BOUNDARY_RADIUS: f32 = 2200.0;
+const BOUNDARY_HARD_RADIUS: f32 = 2900.0;
+const BOUNDARY_PUSH: f32 = 0.02;
+const WAYPOINT_RADIUS: f32 = 80.0;
+const RING_RADIUS: f32 = 25.0;
+const RING_THICKNESS: f32 = 2.5;
+const RING_SEGMENTS: usize = 32;
+const RING_SPLITS_SHOWN: usize = 4;
+const TURN_ASSIST_MAX_BANK: f32 = 70.0;
+const AI_LEAD_ANGLE: f32 = 25.0;
+const AI_MAX_BANK: f32 = 35.0;
+const TRAFFIC_WARNING_DISTANCE: f32 = 300.0;
+const WORLD_HALF_EXTENT: f32 = 2500.0;
+const INPUT_SMOOTHING_TIME: f32 = 0.12;
+const STICK_DEADZONE: f32 = 0.1;
+const DEFAULT_EXPO: f32 = 0.3;
+const MAX_EXPO: f32 = 1.0;
+const EXPO_STEP: f32 = 0.1;
+const EXPO_CURVE_SIZE: f32 = 120.0;
+const MOUSE_FLIGHT_RANGE: f32 = 0.8;
+const MOUSE_FLIGHT_YAW: f32 = 0.3;
+const NOTICE_DURATION: f32 = 2.0;
+const HUD_READOUT_SMOOTHING: f32 = 0.25;
+const MAX_HUD_READOUT_SMOOTHING: f32 = 2.0;
+const FRAME_SAMPLES: usize = 120;
+const MINIMAP_SIZE: f32 = 180.0;
+const PHYSICS_DT: f32 = 1.0 / 120.0;
+const MAX_FRAME_TIME: f32 = 0.25;
+const TIME_SCALES: [f32; 7] = [0.1, 0.25, 0.5, 1.0, 1.5, 2.0, 4.0];
+const DEFAULT_TIME_SCALE: usize = 3;
+const SCORES_PATH: &str = "scores.txt";
+const MAX_LOG_ROWS: usize = 60 * 60 * 10;
+const MAX_REPLAY_FRAMES: usize = 120 * 180;
+const ATTITUDE_RADIUS: f32 = 80.0;
+const ATTITUDE_PX_PER_DEG: f32 = 2.0;
+const TAPE_WIDTH: f32 = 70.0;
+const TAPE_HEIGHT: f32 = 220.0;
+const THROTTLE_BAR_WIDTH: f32 = 16.0;
+const VSI_RADIUS: f32 = 50.0;
+const ILS_SIZE: f32 = 110.0;
+const VSI_RANGE: f32 = 20.0;
+const VSI_SMOOTHING: f32 = 0.3;
+const SLIP_SMOOTHING: f32 = 0.2;
+const SLIP_BALL_WIDTH: f32 = 120.0;
+const SLIP_BALL_RADIUS: f32 = 7.0;
+const SLIP_BALL_SAG: f32 = 10.0;
+const SLIP_BALL_RANGE: f32 = 0.3;
+const ENERGY_SMOOTHING: f32 = 0.5;
+const ENERGY_BAR_MAX: f32 = 3000.0;
+const VSI_SINK_WARNING: f32 = 5.0;
+const HUD_REFERENCE_HEIGHT: f32 = 720.0;
+const HUD_MIN_FIT: f32 = 0.6;
+const HUD_MAX_FIT: f32 = 2.5;
+const HUD_SCALE_STEP: f32 = 0.1;
+const HUD_MIN_SCALE: f32 = 0.5;
+const HUD_MAX_SCALE: f32 = 2.0;
+const MENU_ITEM_WIDTH: f32 = 420.0;
+const MENU_ITEM_HEIGHT: f32 = 50.0;
+const FPM_RADIUS: f32 = 8.0;
+const LADDER_PX_PER_DEG: f32 = 6.0;
+const LADDER_STEP: usize = 10;
+const LADDER_VISIBLE: f32 = 30.0;
+const LADDER_HALF_WIDTH: f32 = 70.0;
+const LADDER_GAP: f32 = 24.0;
+const LADDER_TICK: f32 = 8.0;
+const LADDER_DASHES: usize = 4;
+const FPM_EDGE_MARGIN: f32 = 24.0;
+const COMPASS_WIDTH: f32 = 480.0;
+const COCKPIT_PANEL_HEIGHT: f32 = 240.0;
+const COCKPIT_GAUGE_RADIUS: f32 = 80.0;
+const COCKPIT_GAUGE_SPACING: f32 = 190.0;
+const ALTIMETER_REVOLUTION: f32 = 1000.0;
+const CONTROLS_HINT: &str = "W/S Pitch | A/D Roll | Q/E Yaw | +/- Gaz | B Art Yakıcı | PgUp/PgDn Trim (Shift Roll, Ctrl Yaw) | H Otopilot | Shift+H Oto Gaz (+/- Hedef Hız) | L Üsse Dönüş | K Dönüş Desteği | G İniş Takımı | F/Shift+F Flap | Space Fren/Ateş | C Kokpit | Z Yakınlaştır | V Kamera | Shift+V Kamera Sarsıntısı | Tekerlek/Home/End Takip Mesafesi | I Göstergeler | T Türbülans | [/] Saat | O Engeller | Ins Ufuk Bulutları | F5/F6 Kayıt/Tekrar | F7 Kara Kutu | F8 Hareket Bulanıklığı | F11 Fizik Modu | F12 Ekran Görüntüsü | F1 Ayarlar | F2/Shift+F2 Expo | F3 FPS | F4 Kuvvetler | Shift+F4 Durum Verisi | U Sınırsız Yakıt | Tab Uçak | P Duraklat | ,/. Zaman Hızı | Y Yerçekimi | M Fare Kontrolü | J Ters Pitch | N Birimler | Shift+N Dil | F9/F10 HUD Ölçeği | X Toparla | R Sıfırla | Esc Menü";
+const CONTROLS_HINT_EN: &str = "W/S Pitch | A/D Roll | Q/E Yaw | +/- Throttle | B Afterburner | PgUp/PgDn Trim (Shift Roll, Ctrl Yaw) | H Autopilot | Shift+H Auto-Throttle (+/- Target Speed) | L Return to Base | K Turn Assist | G Landing Gear | F/Shift+F Flaps | Space Brake/Fire | C Cockpit | Z Zoom | V Camera | Shift+V Camera Shake | Wheel/Home/End Chase Distance | I Gauges | T Turbulence | [/] Clock | O Obstacles | Ins Horizon Clouds | F5/F6 Record/Replay | F7 Flight Log | F8 Motion Blur | F11 Physics Mode | F12 Screenshot | F1 Settings | F2/Shift+F2 Expo | F3 FPS | F4 Forces | Shift+F4 State Data | U Unlimited Fuel | Tab Aircraft | P Pause | ,/. Time Scale | Y Gravity | M Mouse Flight | J Invert Pitch | N Units | Shift+N Language | F9/F10 HUD Scale | X Recover | R Reset | Esc Menu";
+const CONTROLS_SHORT: &str = "F1 Ayarlar ve Kontroller / Esc Menü";
+const CONTROLS_SHORT_EN: &str = "F1 Settings & Controls / Esc Menu";
+const CONTROLS_COLUMN_WIDTH: f32 = 640.0;
+const COMPASS_HEIGHT: f32 = 40.0;
+const COMPASS_PX_PER_DEG: f32 = 4.0;
+const ENGINE_SOUND_PATH: &str = "assets/engine.wav";
+const ENGINE_PITCH_BANDS: [f32; 4] = [0.7, 1.0, 1.35, 1.8];
+const STALL_HORN_FREQUENCY: f32 = 1200.0;
+const STALL_HORN_VOLUME: f32 = 0.5;
+const GPWS_ALERT_FREQUENCY: f32 = 700.0;
+const GPWS_ALERT_VOLUME: f32 = 0.6;
 
+#[derive(Clone, Copy)]
+struct AircraftConfig {
+    name: &'static str,
+    drag_coeff: f32,
+    lift_coeff: f32,
+    roll_rate: f32,
+    pitch_rate: f32,
+    yaw_rate: f32,
+    max_speed: f32,
+    min_speed: f32,
+    throttle_step: f32,
+    engine: EngineKind,
+    empty_mass: f32,
+    fuel_mass: f32,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum EngineKind {
+    Propeller,
+    Jet,
+}
+
+const AIRCRAFT_PRESETS: [AircraftConfig; 3] = [
+    AircraftConfig {
+        name: "trainer",
+        drag_coeff: 0.08,
+        lift_coeff: 0.011,
+        roll_rate: 1.4,
+        pitch_rate: 0.9,
+        yaw_rate: 0.4,
+        max_speed: 130.0,
+        min_speed: 12.0,
+        throttle_step: 0.5,
+        engine: EngineKind::Propeller,
+        empty_mass: 850.0,
+        fuel_mass: 150.0,
+    },
+    AircraftConfig {
+        name: "fighter",
+        drag_coeff: 0.05,
+        lift_coeff: 0.010,
+        roll_rate: 3.0,
+        pitch_rate: 1.4,
+        yaw_rate: 0.6,
+        max_speed: 220.0,
+        min_speed: 25.0,
+        throttle_step: 0.7,
+        engine: EngineKind::Jet,
+        empty_mass: 1050.0,
+        fuel_mass: 250.0,
+    },
+    AircraftConfig {
+        name: "glider",
+        drag_coeff: 0.03,
+        lift_coeff: 0.02,
+        roll_rate: 0.9,
+        pitch_rate: 0.7,
+        yaw_rate: 0.5,
+        max_speed: 60.0,
+        min_speed: 8.0,
+        throttle_step: 0.3,
+        engine: EngineKind::Propeller,
+        empty_mass: 520.0,
+        fuel_mass: 40.0,
+    },
+];
+
+const USAGE: &str = "Usage: flight-sim [--altitude METERS] [--speed M/S] [--aircraft trainer|fighter|glider] [--width PX] [--height PX] [--msaa SAMPLES] [--fullscreen] [--invert-pitch] [--units metric|imperial] [--language tr|en] [--hud-scale FACTOR] [--hud-smoothing SECONDS] [--ground-friction COEFF] [--wind X,Y,Z] [--expo AMOUNT] [--no-coach] [--motion-blur] [--no-camera-shake] [--gpws-time SECONDS] [--gpws-height METERS] [--physics arcade|realistic]";
+
+struct LaunchOptions {
+    altitude: f32,
+    speed: f32,
+    aircraft: usize,
+    width: i32,
+    height: i32,
+    msaa: i32,
+    fullscreen: bool,
+    invert_pitch: bool,
+    units: UnitSystem,
+    language: Language,
+    hud_scale: f32,
+    hud_smoothing: f32,
+    ground_friction: f32,
+    wind: Vec3,
+    takeoff_coach: bool,
+    expo: f32,
+    motion_blur: bool,
+    camera_shake: bool,
+    gpws_time: f32,
+    gpws_height: f32,
+    physics: PhysicsMode,
+}
+
+impl Default for LaunchOptions {
+    fn default() -> Self {
+        Self {
+            altitude: 90.0,
+            speed: 50.0,
+            aircraft: 0,
+            width: 1280,
+            height: 720,
+            msaa: 4,
+            fullscreen: false,
+            invert_pitch: false,
+            units: UnitSystem::Metric,
+            language: Language::Turkish,
+            hud_scale: 1.0,
+            hud_smoothing: HUD_READOUT_SMOOTHING,
+            ground_friction: ROLLING_RESISTANCE,
+            wind: DEFAULT_WIND,
+            takeoff_coach: true,
+            expo: DEFAULT_EXPO,
+            motion_blur: false,
+            camera_shake: true,
+            gpws_time: GPWS_WARNING_TIME,
+            gpws_height: GPWS_MAX_HEIGHT,
+            physics: PhysicsMode::Arcade,
+        }
+    }
+}
+
+impl LaunchOptions {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            if arg == "--help" || arg == "-h" {
+                return Ok(None);
+            }
+            if arg == "--fullscreen" {
+                options.fullscreen = true;
+                continue;
+            }
+            if arg == "--invert-pitch" {
+                options.invert_pitch = true;
+                continue;
+            }
+            if arg == "--motion-blur" {
+                options.motion_blur = true;
+                continue;
+            }
+            if arg == "--no-camera-shake" {
+                options.camera_shake = false;
+                continue;
+            }
+            if arg == "--no-coach" {
+                options.takeoff_coach = false;
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", arg))?;
+            let number = || {
+                value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|number| number.is_finite() && *number >= 0.0)
+                    .ok_or_else(|| format!("invalid value for {}: {}", arg, value))
+            };
+            match arg.as_str() {
+                "--altitude" => options.altitude = number()?,
+                "--speed" => options.speed = number()?,
+                "--width" => options.width = number()?.max(320.0) as i32,
+                "--height" => options.height = number()?.max(240.0) as i32,
+                "--msaa" => options.msaa = number()?.min(16.0) as i32,
+                "--expo" => options.expo = number()?.min(MAX_EXPO),
+                "--gpws-time" => options.gpws_time = number()?.min(MAX_GPWS_WARNING_TIME),
+                "--gpws-height" => options.gpws_height = number()?,
+                "--hud-smoothing" => {
+                    options.hud_smoothing = number()?.min(MAX_HUD_READOUT_SMOOTHING)
+                }
+                "--hud-scale" => options.hud_scale = number()?.clamp(HUD_MIN_SCALE, HUD_MAX_SCALE),
+                "--ground-friction" => {
+                    options.ground_friction = number()?.min(MAX_ROLLING_RESISTANCE)
+                }
+                "--physics" => {
+                    options.physics = match value.as_str() {
+                        "arcade" => PhysicsMode::Arcade,
+                        "realistic" => PhysicsMode::Realistic,
+                        _ => return Err(format!("unknown physics mode: {}", value)),
+                    };
+                }
+                "--wind" => {
+                    let components: Vec<f32> = value
+                        .split(',')
+                        .map(|part| part.trim().parse::<f32>())
+                        .collect::<Result<_, _>>()
+                        .ok()
+                        .filter(|components: &Vec<f32>| {
+                            components.len() == 3 && components.iter().all(|c| c.is_finite())
+                        })
+                        .ok_or_else(|| format!("invalid value for {}: {}", arg, value))?;
+                    options.wind = Vec3::from_slice(&components).clamp_length_max(MAX_WIND);
+                }
+                "--language" => {
+                    options.language = match value.as_str() {
+                        "tr" => Language::Turkish,
+                        "en" => Language::English,
+                        _ => return Err(format!("unknown language: {}", value)),
+                    };
+                }
+                "--units" => {
+                    options.units = match value.as_str() {
+                        "metric" => UnitSystem::Metric,
+                        "imperial" => UnitSystem::Imperial,
+                        _ => return Err(format!("unknown units: {}", value)),
+                    };
+                }
+                "--aircraft" => {
+                    options.aircraft = AIRCRAFT_PRESETS
+                        .iter()
+                        .position(|preset| preset.name == value)
+                        .ok_or_else(|| format!("unknown aircraft: {}", value))?;
+                }
+                _ => return Err(format!("unknown argument: {}", arg)),
+            }
+        }
+        Ok(Some(options))
+    }
+}
+
+fn wrap_degrees(angle: f32) -> f32 {
+    (angle + 180.0).rem_euclid(360.0) - 180.0
+}
+
+fn wrap_entries(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for entry in text.split(" | ") {
+        match lines.last_mut() {
+            Some(line) if measure(&format!("{} | {}", line, entry)) <= max_width => {
+                line.push_str(" | ");
+                line.push_str(entry);
+            }
+            _ => lines.push(entry.to_string()),
+        }
+    }
+    lines
+}
+
+fn air_density(altitude: f32) -> f32 {
+    (-altitude.max(0.0) / ATMOSPHERE_SCALE_HEIGHT).exp()
+}
+
+fn terrain_height(x: f32, z: f32) -> f32 {
+    let raw = 28.0 * (x * 0.0023 - 1.3).sin() * (z * 0.0019 + 0.4).cos()
+        + 14.0 * (x * 0.0051 - z * 0.0037 - 2.1).sin()
+        + 6.0 * (x * 0.013 + z * 0.011).sin();
+    let hills = (raw + 10.0).max(0.0) * 1.3;
+    let dx = ((x - RUNWAY_CENTER.x).abs() - RUNWAY_WIDTH * 0.5).max(0.0);
+    let dz = ((z - RUNWAY_CENTER.y).abs() - RUNWAY_LENGTH * 0.5).max(0.0);
+    let t = (Vec2::new(dx, dz).length() / TERRAIN_FLAT_BLEND).min(1.0);
+    hills * t * t * (3.0 - 2.0 * t)
+}
+
+fn terrain_normal(x: f32, z: f32) -> Vec3 {
+    let e = 2.0;
+    Vec3::new(
+        terrain_height(x - e, z) - terrain_height(x + e, z),
+        2.0 * e,
+        terrain_height(x, z - e) - terrain_height(x, z + e),
+    )
+    .normalize()
+}
+
+fn runway_offset(position: Vec3) -> Option<f32> {
+    let lateral = position.x - RUNWAY_CENTER.x;
+    let along = position.z - RUNWAY_CENTER.y;
+    (lateral.abs() <= RUNWAY_WIDTH * 0.5 && along.abs() <= RUNWAY_LENGTH * 0.5).then_some(lateral)
+}
+
+fn runway_approach(position: Vec3) -> Vec2 {
+    if position.z > RUNWAY_CENTER.y {
+        Vec2::NEG_Y
+    } else {
+        Vec2::Y
+    }
+}
+
+fn runway_wind(position: Vec3, wind: Vec3) -> Vec2 {
+    let approach = runway_approach(position);
+    let flat = Vec2::new(wind.x, wind.z);
+    Vec2::new(-flat.dot(approach), flat.dot(approach.perp()))
+}
+
+fn ils_deviation(position: Vec3, heading: f32) -> Option<Vec2> {
+    let approach = runway_approach(position);
+    let threshold = RUNWAY_CENTER - approach * RUNWAY_LENGTH * 0.5;
+    let aim = threshold + approach * ILS_AIM_DISTANCE;
+    let offset = Vec2::new(position.x, position.z) - aim;
+    let distance = -offset.dot(approach);
+    let lateral = offset.dot(approach.perp());
+    let course = approach.x.atan2(-approach.y).to_degrees();
+    if distance <= 0.0 || distance > ILS_RANGE || wrap_degrees(heading - course).abs() > 90.0 {
+        return None;
+    }
+    let lateral_angle = lateral.atan2(distance).to_degrees();
+    if lateral_angle.abs() > LOCALIZER_COVERAGE {
+        return None;
+    }
+    let height = position.y - GROUND_CLEARANCE;
+    let path_angle = height.atan2(distance).to_degrees();
+    Some(Vec2::new(
+        (-lateral_angle / LOCALIZER_FULL_SCALE).clamp(-1.0, 1.0),
+        ((GLIDESLOPE_ANGLE - path_angle) / GLIDESLOPE_FULL_SCALE).clamp(-1.0, 1.0),
+    ))
+}
+
+struct Weather {
+    wind: Vec3,
+    turbulence: bool,
+    time: f32,
+}
+
+impl Weather {
+    fn new(wind: Vec3) -> Self {
+        Self {
+            wind,
+            turbulence: true,
+            time: 0.0,
+        }
+    }
+
+    fn advance(&mut self, dt: f32) {
+        self.time += dt;
+    }
+
+    fn gust(&self) -> Vec3 {
+        if !self.turbulence {
+            return Vec3::ZERO;
+        }
+        let t = self.time;
+        let wave = Vec3::new(
+            (t * 0.9).sin() + (t * 2.3 + 1.7).sin() * 0.5,
+            (t * 1.3 + 0.4).sin() + (t * 3.1).sin() * 0.4,
+            (t * 0.6 + 2.1).sin() * 0.7,
+        );
+        let noise = Vec3::new(
+            rand::gen_range(-0.3, 0.3),
+            rand::gen_range(-0.3, 0.3),
+            rand::gen_range(-0.3, 0.3),
+        );
+        (wave + noise) * TURBULENCE_STRENGTH
+    }
+
+    fn jitter(&self) -> Vec2 {
+        if !self.turbulence {
+            return Vec2::ZERO;
+        }
+        let t = self.time;
+        Vec2::new(
+            (t * 1.7).sin() + (t * 4.3 + 0.5).sin() * 0.5,
+            (t * 1.1 + 2.0).sin() + (t * 3.7).sin() * 0.5,
+        ) * TURBULENCE_JITTER
+    }
+}
+
+struct TimeOfDay {
+    hours: f32,
+}
+
+impl TimeOfDay {
+    fn new() -> Self {
+        Self { hours: 10.0 }
+    }
+
+    fn advance(&mut self, dt: f32, scrub: f32) {
+        let rate = 24.0 / DAY_LENGTH + scrub * TIME_SCRUB_RATE;
+        self.hours = (self.hours + rate * dt).rem_euclid(24.0);
+    }
+
+    fn daylight(&self) -> f32 {
+        let sun = ((self.hours - 6.0) / 12.0 * std::f32::consts::PI).sin();
+        ((sun + 0.15) / 0.45).clamp(0.0, 1.0)
+    }
+
+    fn ambient(&self) -> f32 {
+        0.25 + 0.75 * self.daylight()
+    }
+
+    fn sky_color(&self) -> Color {
+        let light = self.daylight();
+        let night = vec3(0.02, 0.03, 0.08);
+        let day = vec3(36.0, 115.0, 195.0) / 255.0;
+        let dusk = vec3(0.85, 0.45, 0.25);
+        let twilight = 1.0 - (light * 2.0 - 1.0).abs();
+        let sky = night.lerp(day, light).lerp(dusk, twilight * 0.6);
+        Color::new(sky.x, sky.y, sky.z, 1.0)
+    }
+
+    fn horizon_color(&self) -> Color {
+        let sky = self.sky_color();
+        let haze = 0.35 * self.daylight();
+        Color::new(
+            sky.r + (1.0 - sky.r) * haze,
+            sky.g + (1.0 - sky.g) * haze,
+            sky.b + (1.0 - sky.b) * haze,
+            1.0,
+        )
+    }
+
+    fn zenith_color(&self) -> Color {
+        shade(self.sky_color(), 0.6)
+    }
+
+    fn sun_direction(&self) -> Vec3 {
+        let angle = (self.hours - 6.0) / 12.0 * std::f32::consts::PI;
+        let tilt = SUN_TILT.to_radians();
+        Vec3::new(
+            angle.cos(),
+            angle.sin() * tilt.cos(),
+            angle.sin() * tilt.sin(),
+        )
+    }
+
+    fn clock(&self) -> String {
+        let minutes = (self.hours * 60.0) as u32;
+        format!("{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+struct Cloud {
+    center: Vec3,
+    size: Vec3,
+    color: Color,
+    popped: bool,
+}
+
+impl Cloud {
+    fn contains(&self, point: Vec3) -> bool {
+        let half = self.size * 0.5;
+        (point - self.center).abs().cmple(half).all()
+    }
+
+    fn hit_by(&self, start: Vec3, end: Vec3) -> bool {
+        let half = self.size * 0.5;
+        let origin = start - self.center;
+        let delta = end - start;
+        let mut enter: f32 = 0.0;
+        let mut exit: f32 = 1.0;
+        for axis in 0..3 {
+            if delta[axis].abs() < f32::EPSILON {
+                if origin[axis].abs() > half[axis] {
+                    return false;
+                }
+                continue;
+            }
+            let near = (-half[axis] - origin[axis]) / delta[axis];
+            let far = (half[axis] - origin[axis]) / delta[axis];
+            enter = enter.max(near.min(far));
+            exit = exit.min(near.max(far));
+            if enter > exit {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+struct Projectile {
+    position: Vec3,
+    velocity: Vec3,
+    age: f32,
+}
+
+fn cloud_field() -> Vec<Cloud> {
+    let mut clouds = Vec::new();
+    for i in -4..=4 {
+        let offset = i as f32 * 320.0;
+        clouds.push(Cloud {
+            center: Vec3::new(offset, 300.0, 900.0),
+            size: Vec3::splat(14.0),
+            color: Color::new(0.9, 0.97, 1.0, 0.55),
+            popped: false,
+        });
+        clouds.push(Cloud {
+            center: Vec3::new(offset * 1.4, 240.0, -1100.0),
+            size: Vec3::new(22.0, 16.0, 22.0),
+            color: Color::new(0.92, 0.95, 1.0, 0.45),
+            popped: false,
+        });
+    }
+    clouds
+}
+
+struct HorizonCloud {
+    position: Vec3,
+    size: Vec2,
+}
+
+fn horizon_cloud_layer() -> Vec<HorizonCloud> {
+    (0..HORIZON_CLOUD_COUNT)
+        .map(|index| {
+            let i = index as f32;
+            let angle = i * 2.399_963;
+            let radius =
+                HORIZON_CLOUD_MIN_RADIUS + HORIZON_CLOUD_RADIUS_SPREAD * (i * 0.618).fract();
+            HorizonCloud {
+                position: Vec3::new(
+                    angle.cos() * radius,
+                    HORIZON_CLOUD_ALTITUDE + 200.0 * (i * 1.7).sin(),
+                    angle.sin() * radius,
+                ),
+                size: Vec2::new(
+                    500.0 + 300.0 * (i * 0.37).fract(),
+                    120.0 + 60.0 * (i * 0.53).fract(),
+                ),
+            }
+        })
+        .collect()
+}
+
+fn draw_horizon_clouds(clouds: &[HorizonCloud], eye: Vec3, light: f32, sky: Color) {
+    let mut order: Vec<(f32, &HorizonCloud)> = clouds
+        .iter()
+        .map(|cloud| (cloud.position.distance(eye), cloud))
+        .collect();
+    order.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (distance, cloud) in order {
+        let view = (cloud.position - eye) / distance.max(1.0);
+        let elevation = view.y.clamp(-1.0, 1.0).asin().to_degrees().abs();
+        let fade = ((HORIZON_CLOUD_FADE_END - elevation)
+            / (HORIZON_CLOUD_FADE_END - HORIZON_CLOUD_FADE_START))
+            .clamp(0.0, 1.0);
+        if fade <= 0.0 {
+            continue;
+        }
+        let right = view.cross(Vec3::Y).normalize_or(Vec3::X);
+        let up = right.cross(view);
+        let base = fog(
+            shade(Color::new(0.95, 0.96, 1.0, 1.0), light),
+            sky,
+            distance * 0.5,
+        );
+        let center = Color::new(base.r, base.g, base.b, HORIZON_CLOUD_ALPHA * fade);
+        let rim = Color::new(base.r, base.g, base.b, 0.0);
+        let p = cloud.position;
+        let mut vertices = vec![Vertex::new(p.x, p.y, p.z, 0.0, 0.0, center)];
+        let mut indices = Vec::with_capacity(HORIZON_CLOUD_SEGMENTS * 3);
+        for index in 0..HORIZON_CLOUD_SEGMENTS {
+            let angle = index as f32 / HORIZON_CLOUD_SEGMENTS as f32 * std::f32::consts::TAU;
+            let point =
+                p + right * (angle.cos() * cloud.size.x) + up * (angle.sin() * cloud.size.y);
+            vertices.push(Vertex::new(point.x, point.y, point.z, 0.0, 0.0, rim));
+            let next = (index + 1) % HORIZON_CLOUD_SEGMENTS;
+            indices.extend_from_slice(&[0, index as u16 + 1, next as u16 + 1]);
+        }
+        draw_mesh(&Mesh {
+            vertices,
+            indices,
+            texture: None,
+        });
+    }
+}
+
+struct Route {
+    waypoints: Vec<Vec3>,
+    active: usize,
+    course_start: Option<f32>,
+}
+
+impl Route {
+    fn new() -> Self {
+        Self {
+            waypoints: vec![
+                Vec3::new(400.0, 150.0, -1800.0),
+                Vec3::new(1400.0, 250.0, -1200.0),
+                Vec3::new(1200.0, 200.0, 600.0),
+                Vec3::new(-600.0, 150.0, 900.0),
+                Vec3::new(0.0, 70.0, 200.0),
+            ],
+            active: 0,
+            course_start: None,
+        }
+    }
+
+    fn restart(&mut self) {
+        self.active = 0;
+        self.course_start = None;
+    }
+
+    fn target(&self) -> Vec3 {
+        self.waypoints[self.active]
+    }
+
+    fn bearing_from(&self, position: Vec3) -> f32 {
+        let offset = self.target() - position;
+        offset.x.atan2(-offset.z).to_degrees()
+    }
+
+    fn update(&mut self, position: Vec3, time: f32) -> Option<f32> {
+        if position.distance(self.target()) >= WAYPOINT_RADIUS {
+            return None;
+        }
+        let completed = if self.active == 0 {
+            self.course_start = Some(time);
+            None
+        } else if self.active == self.waypoints.len() - 1 {
+            self.course_start.take().map(|start| time - start)
+        } else {
+            None
+        };
+        self.active = (self.active + 1) % self.waypoints.len();
+        completed
+    }
+}
+
+struct RingGate {
+    center: Vec3,
+    normal: Vec3,
+}
+
+impl RingGate {
+    fn crossed_by(&self, from: Vec3, to: Vec3) -> bool {
+        let before = (from - self.center).dot(self.normal);
+        let after = (to - self.center).dot(self.normal);
+        if before >= 0.0 || after < 0.0 {
+            return false;
+        }
+        let point = from.lerp(to, before / (before - after));
+        point.distance(self.center) <= RING_RADIUS
+    }
+
+    fn axes(&self) -> (Vec3, Vec3) {
+        (self.normal.cross(Vec3::Y).normalize(), Vec3::Y)
+    }
+}
+
+struct RingRace {
+    gates: Vec<RingGate>,
+    next: usize,
+    start: Option<f32>,
+    splits: Vec<f32>,
+}
+
+impl RingRace {
+    fn new() -> Self {
+        let centers = [
+            Vec3::new(0.0, 110.0, -400.0),
+            Vec3::new(500.0, 160.0, -1100.0),
+            Vec3::new(1300.0, 220.0, -900.0),
+            Vec3::new(1500.0, 180.0, -100.0),
+            Vec3::new(900.0, 140.0, 600.0),
+            Vec3::new(100.0, 120.0, 1000.0),
+            Vec3::new(-700.0, 170.0, 500.0),
+            Vec3::new(-900.0, 150.0, -300.0),
+        ];
+        let gates = centers
+            .iter()
+            .enumerate()
+            .map(|(index, &center)| {
+                let previous = if index == 0 {
+                    Vec3::ZERO
+                } else {
+                    centers[index - 1]
+                };
+                let next = centers
+                    .get(index + 1)
+                    .copied()
+                    .unwrap_or(center * 2.0 - previous);
+                let heading = next - previous;
+                RingGate {
+                    center,
+                    normal: Vec3::new(heading.x, 0.0, heading.z).normalize(),
+                }
+            })
+            .collect();
+        Self {
+            gates,
+            next: 0,
+            start: None,
+            splits: Vec::new(),
+        }
+    }
+
+    fn restart(&mut self) {
+        self.next = 0;
+        self.start = None;
+        self.splits.clear();
+    }
+
+    fn target(&self) -> &RingGate {
+        &self.gates[self.next]
+    }
+
+    fn elapsed(&self, time: f32) -> Option<f32> {
+        self.start.map(|start| time - start)
+    }
+
+    fn update(&mut self, from: Vec3, to: Vec3, time: f32) -> Option<f32> {
+        if !self.target().crossed_by(from, to) {
+            return None;
+        }
+        if self.next == 0 {
+            self.start = Some(time);
+            self.splits.clear();
+        }
+        let split = self.elapsed(time).unwrap_or(0.0);
+        self.splits.push(split);
+        self.next = (self.next + 1) % self.gates.len();
+        if self.next == 0 {
+            self.start = None;
+            Some(split)
+        } else {
+            None
+        }
+    }
+}
+
+struct Terrain {
+    chunks: Vec<Mesh>,
+    base_colors: Vec<Vec<Color>>,
+}
+
+impl Terrain {
+    fn new() -> Self {
+        let cell = WORLD_HALF_EXTENT * 2.0 / TERRAIN_CELLS as f32;
+        let side = TERRAIN_CHUNK_CELLS + 1;
+        let grass = Color::new(0.25, 0.47, 0.18, 1.0).to_vec();
+        let rock = Color::new(0.46, 0.42, 0.32, 1.0).to_vec();
+        let sun = Vec3::new(0.4, 1.0, 0.3).normalize();
+        let mut chunks = Vec::new();
+        let mut base_colors = Vec::new();
+        for chunk_z in (0..TERRAIN_CELLS).step_by(TERRAIN_CHUNK_CELLS) {
+            for chunk_x in (0..TERRAIN_CELLS).step_by(TERRAIN_CHUNK_CELLS) {
+                let mut vertices = Vec::with_capacity(side * side);
+                let mut colors = Vec::with_capacity(side * side);
+                for row in 0..side {
+                    for column in 0..side {
+                        let x = -WORLD_HALF_EXTENT + (chunk_x + column) as f32 * cell;
+                        let z = -WORLD_HALF_EXTENT + (chunk_z + row) as f32 * cell;
+                        let height = terrain_height(x, z);
+                        let diffuse = 0.65 + 0.35 * terrain_normal(x, z).dot(sun).max(0.0);
+                        let color = shade(
+                            Color::from_vec(grass.lerp(rock, (height / 70.0).clamp(0.0, 1.0))),
+                            diffuse,
+                        );
+                        vertices.push(Vertex::new(x, height, z, 0.0, 0.0, color));
+                        colors.push(color);
+                    }
+                }
+                let mut indices = Vec::with_capacity(TERRAIN_CHUNK_CELLS * TERRAIN_CHUNK_CELLS * 6);
+                for row in 0..TERRAIN_CHUNK_CELLS {
+                    for column in 0..TERRAIN_CHUNK_CELLS {
+                        let corner = (row * side + column) as u16;
+                        let below = corner + side as u16;
+                        indices.extend_from_slice(&[
+                            corner,
+                            below,
+                            corner + 1,
+                            corner + 1,
+                            below,
+                            below + 1,
+                        ]);
+                    }
+                }
+                chunks.push(Mesh {
+                    vertices,
+                    indices,
+                    texture: None,
+                });
+                base_colors.push(colors);
+            }
+        }
+        Self {
+            chunks,
+            base_colors,
+        }
+    }
+
+    fn apply_lighting(&mut self, light: f32, eye: Vec3, sky: Color) {
+        for (mesh, colors) in self.chunks.iter_mut().zip(&self.base_colors) {
+            for (vertex, color) in mesh.vertices.iter_mut().zip(colors) {
+                let distance = vertex.position.distance(eye);
+                vertex.color = fog(shade(*color, light), sky, distance).into();
+            }
+        }
+    }
+
+    fn draw(&self) {
+        for mesh in &self.chunks {
+            draw_mesh(mesh);
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct AiPilot {
+    center: Vec3,
+    radius: f32,
+    clockwise: bool,
+}
+
+impl AiPilot {
+    fn waypoint(&self, angle: f32) -> Vec3 {
+        let angle = angle.to_radians();
+        self.center + Vec3::new(angle.sin(), 0.0, -angle.cos()) * self.radius
+    }
+
+    fn input(&self, plane: &Plane) -> InputState {
+        let from_center = plane.position - self.center;
+        let direction = if self.clockwise { 1.0 } else { -1.0 };
+        let angle = from_center.x.atan2(-from_center.z).to_degrees();
+        let offset = self.waypoint(angle + AI_LEAD_ANGLE * direction) - plane.position;
+        let error = wrap_degrees(offset.x.atan2(-offset.z).to_degrees() - plane.heading());
+        let desired_roll = -(error * 2.5).clamp(-AI_MAX_BANK, AI_MAX_BANK);
+        let roll = ((desired_roll - plane.roll()) / 20.0).clamp(-1.0, 1.0);
+        let yaw = (error / 20.0).clamp(-1.0, 1.0);
+        let desired_pitch = (offset.y * 0.1 - plane.velocity.y * 0.3).clamp(-8.0, 8.0);
+        let pitch = ((desired_pitch - plane.pitch()) / 10.0
+            + plane.roll().abs() / AI_MAX_BANK * 0.15)
+            .clamp(-1.0, 1.0);
+        InputState {
+            roll_right: (-roll).max(0.0),
+            roll_left: roll.max(0.0),
+            pitch_up: pitch.max(0.0),
+            pitch_down: (-pitch).max(0.0),
+            yaw_right: yaw.max(0.0),
+            yaw_left: (-yaw).max(0.0),
+            ..Default::default()
+        }
+    }
+}
+
+struct Traffic {
+    plane: Plane,
+    pilot: AiPilot,
+    previous_position: Vec3,
+    previous_orientation: Quat,
+}
+
+impl Traffic {
+    fn new(pilot: AiPilot) -> Self {
+        let direction = if pilot.clockwise { 1.0 } else { -1.0 };
+        let start = pilot.waypoint(0.0);
+        let launch = LaunchOptions {
+            altitude: start.y,
+            speed: 60.0,
+            ..Default::default()
+        };
+        let mut plane = Plane::new(AIRCRAFT_PRESETS[launch.aircraft], &launch);
+        plane.place_at(start, pilot.waypoint(AI_LEAD_ANGLE * direction) - start);
+        plane.unlimited_fuel = true;
+        plane.expo = 0.0;
+        Self {
+            previous_position: plane.position,
+            previous_orientation: plane.orientation,
+            plane,
+            pilot,
+        }
+    }
+
+    fn step(&mut self, dt: f32, weather: &Weather, gravity: Vec3) {
+        self.previous_position = self.plane.position;
+        self.previous_orientation = self.plane.orientation;
+        let input = self.pilot.input(&self.plane);
+        self.plane.update(dt, &input, weather, gravity);
+        if self.plane.crashed {
+            *self = Traffic::new(self.pilot);
+        }
+    }
+
+    fn rendered(&self, alpha: f32) -> Plane {
+        let mut plane = self.plane.clone();
+        plane.position = self.previous_position.lerp(self.plane.position, alpha);
+        plane.orientation = self
+            .previous_orientation
+            .slerp(self.plane.orientation, alpha);
+        plane
+    }
+}
+
+fn traffic_fleet() -> Vec<Traffic> {
+    [
+        (Vec3::new(900.0, 280.0, -500.0), 900.0, true),
+        (Vec3::new(-1000.0, 420.0, 400.0), 800.0, false),
+        (Vec3::new(0.0, 180.0, -900.0), 650.0, true),
+    ]
+    .into_iter()
+    .map(|(center, radius, clockwise)| {
+        Traffic::new(AiPilot {
+            center,
+            radius,
+            clockwise,
+        })
+    })
+    .collect()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PhysicsMode {
+    Arcade,
+    Realistic,
+}
+
+impl PhysicsMode {
+    fn next(self) -> Self {
+        match self {
+            PhysicsMode::Arcade => PhysicsMode::Realistic,
+            PhysicsMode::Realistic => PhysicsMode::Arcade,
+        }
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        match self {
+            PhysicsMode::Arcade => "arcade",
+            PhysicsMode::Realistic => language.text("gerçekçi", "realistic"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Language {
+    Turkish,
+    English,
+}
+
+impl Language {
+    fn next(self) -> Self {
+        match self {
+            Language::Turkish => Language::English,
+            Language::English => Language::Turkish,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Language::Turkish => "Türkçe",
+            Language::English => "English",
+        }
+    }
+
+    fn text(self, turkish: &'static str, english: &'static str) -> &'static str {
+        match self {
+            Language::Turkish => turkish,
+            Language::English => english,
+        }
+    }
+
+    fn on_off(self, value: bool) -> &'static str {
+        match value {
+            true => self.text("açık", "on"),
+            false => self.text("kapalı", "off"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum UnitSystem {
+    Metric,
+    Imperial,
+}
+
+impl UnitSystem {
+    fn next(self) -> Self {
+        match self {
+            UnitSystem::Metric => UnitSystem::Imperial,
+            UnitSystem::Imperial => UnitSystem::Metric,
+        }
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        match self {
+            UnitSystem::Metric => language.text("metrik", "metric"),
+            UnitSystem::Imperial => language.text("imperyal", "imperial"),
+        }
+    }
+
+    fn speed(self, meters_per_second: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => meters_per_second * MS_TO_KMH,
+            UnitSystem::Imperial => meters_per_second * MS_TO_KNOTS,
+        }
+    }
+
+    fn speed_unit(self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "km/h",
+            UnitSystem::Imperial => "kt",
+        }
+    }
+
+    fn speed_step(self) -> f32 {
+        match self {
+            UnitSystem::Metric => 10.0,
+            UnitSystem::Imperial => 5.0,
+        }
+    }
+
+    fn altitude(self, meters: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => meters,
+            UnitSystem::Imperial => meters * METERS_TO_FEET,
+        }
+    }
+
+    fn altitude_unit(self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "m",
+            UnitSystem::Imperial => "ft",
+        }
+    }
+
+    fn altitude_step(self) -> f32 {
+        match self {
+            UnitSystem::Metric => 10.0,
+            UnitSystem::Imperial => 50.0,
+        }
+    }
+
+    fn vertical_speed(self, meters_per_second: f32) -> f32 {
+        match self {
+            UnitSystem::Metric => meters_per_second,
+            UnitSystem::Imperial => meters_per_second * METERS_TO_FEET * 60.0,
+        }
+    }
+
+    fn vertical_speed_unit(self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "m/s",
+            UnitSystem::Imperial => "ft/min",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GravityMode {
+    Earth,
+    Moon,
+    Zero,
+}
+
+impl GravityMode {
+    fn next(self) -> Self {
+        match self {
+            GravityMode::Earth => GravityMode::Moon,
+            GravityMode::Moon => GravityMode::Zero,
+            GravityMode::Zero => GravityMode::Earth,
+        }
+    }
+
+    fn vector(self) -> Vec3 {
+        match self {
+            GravityMode::Earth => GRAVITY,
+            GravityMode::Moon => MOON_GRAVITY,
+            GravityMode::Zero => Vec3::ZERO,
+        }
+    }
+
+    fn label(self, language: Language) -> &'static str {
+        match self {
+            GravityMode::Earth => language.text("Dünya", "Earth"),
+            GravityMode::Moon => language.text("Ay", "Moon"),
+            GravityMode::Zero => language.text("Sıfır", "Zero"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Scenario {
+    FreeFlight,
+    Takeoff,
+    LandingPractice,
+    CrosswindLanding,
+    WaypointRace,
+    RingRace,
+    ObstacleCourse,
+    TargetPractice,
+}
+
+const SCENARIOS: [Scenario; 8] = [
+    Scenario::FreeFlight,
+    Scenario::Takeoff,
+    Scenario::LandingPractice,
+    Scenario::CrosswindLanding,
+    Scenario::WaypointRace,
+    Scenario::RingRace,
+    Scenario::ObstacleCourse,
+    Scenario::TargetPractice,
+];
+
+impl Scenario {
+    fn label(self, language: Language) -> &'static str {
+        match self {
+            Scenario::FreeFlight => language.text("Serbest Uçuş", "Free Flight"),
+            Scenario::Takeoff => language.text("Kalkış Eğitimi", "Takeoff Training"),
+            Scenario::LandingPractice => language.text("İniş Pratiği", "Landing Practice"),
+            Scenario::CrosswindLanding => language.text("Yan Rüzgar İnişi", "Crosswind Landing"),
+            Scenario::WaypointRace => language.text("Rota Yarışı", "Waypoint Race"),
+            Scenario::RingRace => language.text("Halka Yarışı", "Ring Race"),
+            Scenario::ObstacleCourse => language.text("Engel Parkuru", "Obstacle Course"),
+            Scenario::TargetPractice => language.text("Hedef Atışı", "Target Practice"),
+        }
+    }
+
+    fn is_landing(self) -> bool {
+        matches!(self, Scenario::LandingPractice | Scenario::CrosswindLanding)
+    }
+
+    fn start_plane(self, config: AircraftConfig, launch: &LaunchOptions) -> Plane {
+        let mut plane = if self == Scenario::Takeoff {
+            Plane::on_runway(config, launch)
+        } else {
+            Plane::new(config, launch)
+        };
+        match self {
+            Scenario::LandingPractice | Scenario::CrosswindLanding => {
+                let aim = RUNWAY_CENTER.y + RUNWAY_LENGTH * 0.5 - ILS_AIM_DISTANCE;
+                let height = APPROACH_DISTANCE * GLIDESLOPE_ANGLE.to_radians().tan();
+                plane.place_at(
+                    Vec3::new(
+                        RUNWAY_CENTER.x,
+                        height + GROUND_CLEARANCE,
+                        aim + APPROACH_DISTANCE,
+                    ),
+                    Vec3::NEG_Z,
+                );
+                plane.velocity = Vec3::NEG_Z * APPROACH_SPEED;
+                plane.gear_down = true;
+                plane.gear_extension = 1.0;
+                plane.flaps = 0.5;
+                plane.throttle = 0.4;
+                plane.engine_rpm = 0.4;
+            }
+            Scenario::WaypointRace => {
+                let start = plane.position;
+                plane.place_at(start, Route::new().target() - start);
+            }
+            Scenario::RingRace => {
+                let start = plane.position;
+                plane.place_at(start, RingRace::new().target().center - start);
+            }
+            Scenario::FreeFlight
+            | Scenario::Takeoff
+            | Scenario::ObstacleCourse
+            | Scenario::TargetPractice => {}
+        }
+        plane
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CoachStep {
+    Throttle,
+    Accelerate,
+    Rotate,
+    GearUp,
+    Climb,
+    Done,
+}
+
+struct TakeoffCoach {
+    step: CoachStep,
+    done_time: f32,
+}
+
+impl TakeoffCoach {
+    fn new() -> Self {
+        Self {
+            step: CoachStep::Throttle,
+            done_time: 0.0,
+        }
+    }
+
+    fn rotate_speed(plane: &Plane) -> f32 {
+        plane.config.min_speed * ROTATE_SPEED_RATIO
+    }
+
+    fn finished(&self) -> bool {
+        self.step == CoachStep::Done && self.done_time >= COACH_DONE_TIME
+    }
+
+    fn update(&mut self, plane: &Plane, dt: f32) {
+        let height = plane.position.y
+            - terrain_height(plane.position.x, plane.position.z)
+            - GROUND_CLEARANCE;
+        self.step = match self.step {
+            CoachStep::Throttle if plane.throttle >= 1.0 => CoachStep::Accelerate,
+            CoachStep::Accelerate if plane.velocity.length() >= Self::rotate_speed(plane) => {
+                CoachStep::Rotate
+            }
+            CoachStep::Throttle | CoachStep::Accelerate | CoachStep::Rotate if !plane.on_ground => {
+                CoachStep::GearUp
+            }
+            CoachStep::GearUp if !plane.gear_down => CoachStep::Climb,
+            CoachStep::Climb if height >= COACH_CLIMB_HEIGHT && plane.vertical_speed > 0.0 => {
+                CoachStep::Done
+            }
+            step => step,
+        };
+        if self.step == CoachStep::Done {
+            self.done_time += dt;
+        }
+    }
+
+    fn prompt(&self, plane: &Plane, units: UnitSystem, language: Language) -> Option<String> {
+        if self.finished() {
+            return None;
+        }
+        Some(match self.step {
+            CoachStep::Throttle => language
+                .text("Gazı tam açın (+)", "Full throttle (+)")
+                .to_string(),
+            CoachStep::Accelerate => format!(
+                "{}: {:.0}/{:.0} {}",
+                language.text("Hızlanın", "Accelerate"),
+                units.speed(plane.velocity.length()),
+                units.speed(Self::rotate_speed(plane)),
+                units.speed_unit()
+            ),
+            CoachStep::Rotate => format!(
+                "{} - {:.0} {}",
+                language.text("Burnu kaldırın (W)", "Raise the nose (W)"),
+                units.speed(Self::rotate_speed(plane)),
+                units.speed_unit()
+            ),
+            CoachStep::GearUp => language
+                .text("İniş takımını toplayın (G)", "Retract the landing gear (G)")
+                .to_string(),
+            CoachStep::Climb => format!(
+                "{}: {:.0} {}",
+                language.text("Tırmanışı sürdürün", "Keep climbing"),
+                units.altitude(COACH_CLIMB_HEIGHT),
+                units.altitude_unit()
+            ),
+            CoachStep::Done => language
+                .text("Kalkış tamamlandı!", "Takeoff complete!")
+                .to_string(),
+        })
+    }
+}
+
+#[derive(Default)]
+struct RotationCounter {
+    angle: f32,
+    idle: f32,
+}
+
+impl RotationCounter {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn advance(&mut self, delta: f32, dt: f32) -> bool {
+        if (delta / dt).abs() < AEROBATIC_MIN_RATE.to_radians() {
+            self.idle += dt;
+            if self.idle > AEROBATIC_TIMEOUT {
+                self.reset();
+            }
+            return false;
+        }
+        self.idle = 0.0;
+        if self.angle * delta < 0.0 {
+            self.angle = 0.0;
+        }
+        self.angle += delta;
+        if self.angle.abs() < std::f32::consts::TAU {
+            return false;
+        }
+        self.angle -= std::f32::consts::TAU * self.angle.signum();
+        true
+    }
+}
+
+#[derive(Default)]
+struct Aerobatics {
+    roll: RotationCounter,
+    pitch: RotationCounter,
+    passed_vertical: bool,
+    rolls: u32,
+    loops: u32,
+    points: u32,
+    banner: &'static str,
+    banner_time: f32,
+}
+
+impl Aerobatics {
+    fn update(&mut self, previous: Quat, plane: &Plane, dt: f32) {
+        self.banner_time = (self.banner_time - dt).max(0.0);
+        if plane.on_ground || plane.crashed {
+            self.roll.reset();
+            self.pitch.reset();
+            self.passed_vertical = false;
+            return;
+        }
+        let delta = previous.inverse() * plane.orientation;
+        let delta = if delta.w < 0.0 { -delta } else { delta };
+        let body = delta.to_scaled_axis();
+        if self.roll.advance(-body.z, dt) {
+            self.rolls += 1;
+            self.points += ROLL_POINTS;
+            self.announce("ROLL!");
+        }
+        if self.pitch.angle == 0.0 {
+            self.passed_vertical = false;
+        }
+        if plane.pitch().abs() > AEROBATIC_LOOP_PITCH {
+            self.passed_vertical = true;
+        }
+        if self.pitch.advance(body.x, dt) && self.passed_vertical {
+            self.loops += 1;
+            self.points += LOOP_POINTS;
+            self.passed_vertical = false;
+            self.announce("LOOP!");
+        }
+    }
+
+    fn announce(&mut self, banner: &'static str) {
+        self.banner = banner;
+        self.banner_time = AEROBATIC_BANNER_TIME;
+    }
+
+    fn banner(&self) -> Option<&'static str> {
+        (self.banner_time > 0.0).then_some(self.banner)
+    }
+}
+
+struct World {
+    weather: Weather,
+    time_of_day: TimeOfDay,
+    clouds: Vec<Cloud>,
+    solid_clouds: bool,
+    horizon_clouds: Vec<HorizonCloud>,
+    horizon_layer: bool,
+    route: Route,
+    rings: RingRace,
+    terrain: Terrain,
+    traffic: Vec<Traffic>,
+    gravity: GravityMode,
+    scores: BestScores,
+    scenario: Scenario,
+    projectiles: Vec<Projectile>,
+    targets_hit: u32,
+    coach: Option<TakeoffCoach>,
+    aerobatics: Aerobatics,
+    base_wind: Vec3,
+}
+
+impl World {
+    fn new(wind: Vec3) -> Self {
+        Self {
+            weather: Weather::new(wind),
+            time_of_day: TimeOfDay::new(),
+            clouds: cloud_field(),
+            solid_clouds: false,
+            horizon_clouds: horizon_cloud_layer(),
+            horizon_layer: true,
+            route: Route::new(),
+            rings: RingRace::new(),
+            terrain: Terrain::new(),
+            traffic: traffic_fleet(),
+            gravity: GravityMode::Earth,
+            scores: BestScores::load(),
+            scenario: Scenario::FreeFlight,
+            projectiles: Vec::with_capacity(MAX_PROJECTILES),
+            targets_hit: 0,
+            coach: None,
+            aerobatics: Aerobatics::default(),
+            base_wind: wind,
+        }
+    }
+
+    fn start(&mut self, scenario: Scenario) {
+        self.scenario = scenario;
+        self.solid_clouds = scenario == Scenario::ObstacleCourse;
+        self.weather.wind = if scenario == Scenario::CrosswindLanding {
+            CROSSWIND
+        } else {
+            self.base_wind
+        };
+        self.route.restart();
+        self.rings.restart();
+        self.reset_targets();
+        self.aerobatics = Aerobatics::default();
+    }
+
+    fn restart_coach(&mut self, enabled: bool) {
+        self.coach = (enabled && self.scenario == Scenario::Takeoff).then(TakeoffCoach::new);
+    }
+
+    fn reset_targets(&mut self) {
+        self.clouds = cloud_field();
+        self.projectiles.clear();
+        self.targets_hit = 0;
+    }
+
+    fn targets_left(&self) -> usize {
+        self.clouds.iter().filter(|cloud| !cloud.popped).count()
+    }
+
+    fn fire(&mut self, plane: &Plane) {
+        if self.projectiles.len() >= MAX_PROJECTILES {
+            return;
+        }
+        let forward = plane.forward();
+        self.projectiles.push(Projectile {
+            position: plane.position + forward * PROJECTILE_MUZZLE_OFFSET,
+            velocity: plane.velocity + forward * PROJECTILE_SPEED,
+            age: 0.0,
+        });
+    }
+
+    fn update_projectiles(&mut self, dt: f32) {
+        let clouds = &mut self.clouds;
+        let mut hits = 0;
+        self.projectiles.retain_mut(|projectile| {
+            projectile.age += dt;
+            let end = projectile.position + projectile.velocity * dt;
+            if let Some(cloud) = clouds
+                .iter_mut()
+                .find(|cloud| !cloud.popped && cloud.hit_by(projectile.position, end))
+            {
+                cloud.popped = true;
+                hits += 1;
+                return false;
+            }
+            projectile.position = end;
+            projectile.age < PROJECTILE_LIFETIME && end.y > terrain_height(end.x, end.z)
+        });
+        self.targets_hit += hits;
+        if self.targets_left() == 0 {
+            self.clouds = cloud_field();
+        }
+    }
+
+    fn cloud_collision(&self, position: Vec3) -> bool {
+        self.solid_clouds
+            && self
+                .clouds
+                .iter()
+                .any(|cloud| !cloud.popped && cloud.contains(position))
+    }
+}
+
+fn shade(color: Color, light: f32) -> Color {
+    Color::new(color.r * light, color.g * light, color.b * light, color.a)
+}
+
+fn fog(color: Color, sky: Color, distance: f32) -> Color {
+    let clear = (-distance * FOG_DENSITY).exp();
+    Color::new(
+        sky.r + (color.r - sky.r) * clear,
+        sky.g + (color.g - sky.g) * clear,
+        sky.b + (color.b - sky.b) * clear,
+        color.a,
+    )
+}
+
+#[derive(Clone, Copy, Default)]
+struct Forces {
+    thrust: Vec3,
+    lift: Vec3,
+    drag: Vec3,
+    gravity: Vec3,
+}
+
+#[derive(Clone)]
 struct Plane {
+    config: AircraftConfig,
+    position: Vec3,
+    velocity: Vec3,
+    air_velocity: Vec3,
+    orientation: Quat,
+    throttle: f32,
+    engine_rpm: f32,
+    propeller_angle: f32,
+    pitch_trim: f32,
+    roll_trim: f32,
+    yaw_trim: f32,
+    autopilot: bool,
+    autopilot_altitude: f32,
+    autopilot_integral: f32,
+    auto_throttle: bool,
+    auto_throttle_speed: f32,
+    return_to_base: bool,
+    turn_assist: bool,
+    pitch_inverted: bool,
+    expo: f32,
+    physics: PhysicsMode,
+    stalled: bool,
+    spin_direction: f32,
+    spin_recovery: f32,
+    spin_cooldown: f32,
+    surface_deflection: Vec3,
+    g_force: f32,
+    lift_ratio: f32,
+    forces: Forces,
+    energy_rate: f32,
+    blackout: f32,
+    redout: f32,
+    vertical_speed: f32,
+    lateral_g: f32,
+    over_g_time: f32,
+    fuel: f32,
+    unlimited_fuel: bool,
+    afterburner: bool,
+    afterburner_heat: f32,
+    afterburner_overheated: bool,
+    gear_down: bool,
+    gear_extension: f32,
+    flaps: f32,
+    crashed: bool,
+    respawn_timer: f32,
+    on_ground: bool,
+    rolling_resistance: f32,
+    gpws_time: f32,
+    gpws_height: f32,
+    landing_score: Option<f32>,
+}
+
+impl Plane {
+    fn new(config: AircraftConfig, launch: &LaunchOptions) -> Self {
+        let altitude = launch
+            .altitude
+            .max(terrain_height(0.0, 0.0) + GROUND_CLEARANCE);
+        Self {
+            config,
+            position: Vec3::new(0.0, altitude, 0.0),
+            velocity: Vec3::new(0.0, 0.0, -launch.speed),
+            air_velocity: Vec3::new(0.0, 0.0, -launch.speed),
+            orientation: Quat::IDENTITY,
+            throttle: 0.7,
+            engine_rpm: 0.7,
+            propeller_angle: 0.0,
+            pitch_trim: 0.0,
+            roll_trim: 0.0,
+            yaw_trim: 0.0,
+            autopilot: false,
+            autopilot_altitude: 0.0,
+            autopilot_integral: 0.0,
+            auto_throttle: false,
+            auto_throttle_speed: 0.0,
+            return_to_base: false,
+            turn_assist: false,
+            pitch_inverted: launch.invert_pitch,
+            expo: launch.expo,
+            physics: launch.physics,
+            stalled: false,
+            spin_direction: 0.0,
+            spin_recovery: 0.0,
+            spin_cooldown: 0.0,
+            surface_deflection: Vec3::ZERO,
+            g_force: 1.0,
+            lift_ratio: 0.0,
+            forces: Forces::default(),
+            energy_rate: 0.0,
+            blackout: 0.0,
+            redout: 0.0,
+            vertical_speed: 0.0,
+            lateral_g: 0.0,
+            over_g_time: 0.0,
+            fuel: MAX_FUEL,
+            unlimited_fuel: false,
+            afterburner: false,
+            afterburner_heat: 0.0,
+            afterburner_overheated: false,
+            gear_down: false,
+            gear_extension: 0.0,
+            flaps: 0.0,
+            crashed: false,
+            respawn_timer: RESPAWN_DELAY,
+            on_ground: false,
+            rolling_resistance: launch.ground_friction,
+            gpws_time: launch.gpws_time,
+            gpws_height: launch.gpws_height,
+            landing_score: None,
+        }
+    }
+
+    fn on_runway(config: AircraftConfig, launch: &LaunchOptions) -> Self {
+        let mut plane = Self::new(config, launch);
+        let start = RUNWAY_CENTER.y + RUNWAY_LENGTH * 0.5 - TAKEOFF_START_INSET;
+        let ground = terrain_height(RUNWAY_CENTER.x, start) + GROUND_CLEARANCE;
+        plane.place_at(Vec3::new(RUNWAY_CENTER.x, ground, start), Vec3::NEG_Z);
+        plane.velocity = Vec3::ZERO;
+        plane.air_velocity = Vec3::ZERO;
+        plane.on_ground = true;
+        plane.gear_down = true;
+        plane.gear_extension = 1.0;
+        plane.throttle = IDLE_THROTTLE;
+        plane.engine_rpm = IDLE_THROTTLE;
+        plane
+    }
+
+    fn forward(&self) -> Vec3 {
+        self.orientation * Vec3::new(0.0, 0.0, -1.0)
+    }
+
+    fn right(&self) -> Vec3 {
+        self.orientation * Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    fn up(&self) -> Vec3 {
+        self.orientation * Vec3::new(0.0, 1.0, 0.0)
+    }
+
+    fn pitch(&self) -> f32 {
+        self.forward().y.clamp(-1.0, 1.0).asin().to_degrees()
+    }
+
+    fn roll(&self) -> f32 {
+        self.right().y.atan2(self.up().y).to_degrees()
+    }
+
+    fn turn_assist(&self, gravity: Vec3) -> Vec3 {
+        if !self.turn_assist || self.on_ground {
+            return Vec3::ZERO;
+        }
+        let bank = self
+            .roll()
+            .clamp(-TURN_ASSIST_MAX_BANK, TURN_ASSIST_MAX_BANK);
+        let speed = self.velocity.length().max(self.config.min_speed);
+        let turn_rate = gravity.length() * bank.to_radians().tan() / speed;
+        let body_rate = self.orientation.inverse() * Vec3::Y * turn_rate;
+        let authority = self.control_authority().max(CONTROL_MIN_AUTHORITY);
+        let pitch = body_rate.x / (self.config.pitch_rate * authority);
+        let yaw = body_rate.y / (self.config.yaw_rate * authority);
+        let roll = yaw * self.config.yaw_rate * YAW_ROLL_COUPLING / self.config.roll_rate;
+        Vec3::new(pitch, yaw, roll)
+    }
+
+    fn toggle_autopilot(&mut self) {
+        self.autopilot = !self.autopilot;
+        self.return_to_base = false;
+        self.autopilot_altitude = self.position.y;
+        self.autopilot_integral = 0.0;
+    }
+
+    fn toggle_auto_throttle(&mut self) {
+        self.auto_throttle = !self.auto_throttle;
+        self.auto_throttle_speed = self.indicated_airspeed().max(self.config.min_speed);
+    }
+
+    fn speed_hold(&mut self, setpoint_delta: f32, dt: f32) -> f32 {
+        self.auto_throttle_speed = (self.auto_throttle_speed
+            + setpoint_delta * AT_SETPOINT_RATE * dt)
+            .clamp(self.config.min_speed, self.config.max_speed);
+        (AT_KP * (self.auto_throttle_speed - self.indicated_airspeed())).clamp(-1.0, 1.0)
+    }
+
+    fn toggle_return_to_base(&mut self) {
+        self.return_to_base = !self.return_to_base;
+        self.autopilot = self.return_to_base;
+        self.autopilot_altitude = RTB_ALTITUDE;
+        self.autopilot_integral = 0.0;
+    }
+
+    fn base_offset(&self) -> Vec2 {
+        RUNWAY_CENTER - Vec2::new(self.position.x, self.position.z)
+    }
+
+    fn return_to_base_steering(&self) -> Vec2 {
+        let offset = self.base_offset();
+        let error = if offset.length() < RTB_ARRIVAL_RADIUS {
+            0.0
+        } else {
+            wrap_degrees(offset.x.atan2(-offset.y).to_degrees() - self.heading())
+        };
+        let desired_roll = -(error * 2.5).clamp(-RTB_MAX_BANK, RTB_MAX_BANK);
+        let roll = ((desired_roll - self.roll()) / 20.0).clamp(-1.0, 1.0);
+        let yaw = (error / 20.0).clamp(-1.0, 1.0);
+        Vec2::new(-yaw, -roll)
+    }
+
+    fn altitude_hold(&mut self, dt: f32) -> f32 {
+        let error = self.autopilot_altitude - self.position.y;
+        self.autopilot_integral = (self.autopilot_integral + error * dt).clamp(-60.0, 60.0);
+        let target_pitch = (AP_KP * error + AP_KI * self.autopilot_integral
+            - AP_KD * self.velocity.y)
+            .clamp(-AP_MAX_PITCH, AP_MAX_PITCH);
+        ((target_pitch - self.pitch()) / 10.0).clamp(-1.0, 1.0)
+    }
+
+    fn energy_height(&self, gravity: Vec3) -> Option<f32> {
+        let g = -gravity.y;
+        (g > 0.0).then(|| self.position.y + self.velocity.length_squared() / (2.0 * g))
+    }
+
+    fn indicated_airspeed(&self) -> f32 {
+        self.air_velocity.length() * air_density(self.position.y).sqrt()
+    }
+
+    fn fuel_percent(&self) -> f32 {
+        self.fuel / MAX_FUEL * 100.0
+    }
+
+    fn angle_of_attack(&self) -> f32 {
+        if self.air_velocity.length_squared() < 1.0 {
+            return 0.0;
+        }
+        let along = Vec3::dot(self.air_velocity, self.forward());
+        let normal = -Vec3::dot(self.air_velocity, self.up());
+        normal.atan2(along).to_degrees()
+    }
+
+    fn heading(&self) -> f32 {
+        let forward = self.forward();
+        forward.x.atan2(-forward.z).to_degrees()
+    }
+
+    fn crab_angle(&self) -> f32 {
+        if self.velocity.xz().length_squared() < 1.0 {
+            return 0.0;
+        }
+        let track = self.velocity.x.atan2(-self.velocity.z).to_degrees();
+        wrap_degrees(self.heading() - track)
+    }
+
+    fn heading_orientation(&self) -> Quat {
+        let forward = self.forward();
+        let up = self.up();
+        let flat = Vec2::new(forward.x, forward.z)
+            .try_normalize()
+            .or_else(|| Vec2::new(up.x, up.z).try_normalize())
+            .unwrap_or(Vec2::NEG_Y);
+        Quat::from_rotation_y((-flat.x).atan2(-flat.y))
+    }
+
+    fn level_orientation(&self) -> Quat {
+        self.heading_orientation() * Quat::from_rotation_x(RECOVER_PITCH.to_radians())
+    }
+
+    fn is_spinning(&self) -> bool {
+        self.spin_direction != 0.0
+    }
+
+    fn update_spin(&mut self, yaw_input: f32, stick: Vec2, recover: bool, dt: f32) {
+        self.spin_cooldown = (self.spin_cooldown - dt).max(0.0);
+        if !self.is_spinning() {
+            let yawing = yaw_input.abs() > SPIN_ENTRY_YAW;
+            let slipping = self.sideslip().abs() > SPIN_ENTRY_SIDESLIP;
+            if self.physics == PhysicsMode::Realistic
+                && self.stalled
+                && !self.on_ground
+                && self.spin_cooldown <= 0.0
+                && (yawing || slipping)
+            {
+                self.spin_direction = if yawing {
+                    yaw_input.signum()
+                } else {
+                    self.sideslip().signum()
+                };
+                self.spin_recovery = 0.0;
+            }
+            return;
+        }
+        let opposite_rudder = yaw_input * self.spin_direction < -SPIN_RECOVERY_RUDDER;
+        let centered = stick.abs().max_element() < SPIN_STICK_CENTER;
+        self.spin_recovery = if recover || (opposite_rudder && centered) {
+            self.spin_recovery + dt
+        } else {
+            0.0
+        };
+        if self.spin_recovery >= SPIN_RECOVERY_TIME || self.on_ground || self.crashed {
+            self.spin_direction = 0.0;
+            self.spin_cooldown = SPIN_REENTRY_DELAY;
+        }
+    }
+
+    fn place_at(&mut self, position: Vec3, direction: Vec3) {
+        let flat = Vec2::new(direction.x, direction.z).normalize_or(Vec2::NEG_Y);
+        self.position = position;
+        self.orientation = Quat::from_rotation_y((-flat.x).atan2(-flat.y));
+        self.velocity = self.forward() * self.velocity.length();
+        self.air_velocity = self.velocity;
+    }
+
+    fn horizontal_distance(&self) -> f32 {
+        Vec2::new(self.position.x, self.position.z).length()
+    }
+
+    fn boundary_excess(&self) -> f32 {
+        (self.horizontal_distance() - BOUNDARY_RADIUS).max(0.0)
+    }
+
+    fn boundary_turn(&self) -> f32 {
+        let excess = self.boundary_excess();
+        if excess <= 0.0 {
+            return 0.0;
+        }
+        let heading = self.heading();
+        let home = (-self.position.x).atan2(self.position.z).to_degrees();
+        let relative = wrap_degrees(home - heading);
+        let strength = (excess / (BOUNDARY_HARD_RADIUS - BOUNDARY_RADIUS)).min(1.0);
+        (-relative / 30.0).clamp(-1.0, 1.0) * strength
+    }
+
+    fn sideslip(&self) -> f32 {
+        if self.air_velocity.length_squared() < 1.0 {
+            return 0.0;
+        }
+        let along = Vec3::dot(self.air_velocity, self.forward());
+        let lateral = Vec3::dot(self.air_velocity, self.right());
+        lateral.atan2(along).to_degrees()
+    }
+
+    fn stall_warning(&self) -> bool {
+        !self.on_ground && !self.crashed && self.angle_of_attack().abs() > STALL_WARNING_AOA
+    }
+
+    fn impact_time(&self) -> Option<f32> {
+        let sink = -self.vertical_speed;
+        if self.on_ground || self.crashed || sink < GPWS_MIN_SINK {
+            return None;
+        }
+        let height =
+            self.position.y - terrain_height(self.position.x, self.position.z) - GROUND_CLEARANCE;
+        (height < self.gpws_height).then(|| height.max(0.0) / sink)
+    }
+
+    fn pull_up_warning(&self) -> bool {
+        self.impact_time().is_some_and(|time| time < self.gpws_time)
+    }
+
+    fn step_flaps(&mut self, direction: f32) {
+        self.flaps = (self.flaps + direction * FLAP_STEP).clamp(0.0, 1.0);
+    }
+
+    fn mass(&self) -> f32 {
+        self.config.empty_mass + self.config.fuel_mass * self.fuel / MAX_FUEL
+    }
+
+    fn mass_ratio(&self) -> f32 {
+        self.mass() / REFERENCE_MASS
+    }
+
+    fn control_authority(&self) -> f32 {
+        let airspeed = Vec3::dot(self.air_velocity, self.forward());
+        let authority = if airspeed < self.config.min_speed {
+            CONTROL_STALL_AUTHORITY
+        } else {
+            let t = ((airspeed - self.config.min_speed)
+                / (self.config.max_speed - self.config.min_speed))
+                .min(1.0);
+            CONTROL_MIN_AUTHORITY + (CONTROL_MAX_AUTHORITY - CONTROL_MIN_AUTHORITY) * t
+        };
+        authority / self.mass_ratio().sqrt()
+    }
+
+    fn update(&mut self, dt: f32, input: &InputState, weather: &Weather, gravity: Vec3) {
+        let assist = match self.physics {
+            PhysicsMode::Arcade => self.turn_assist(gravity),
+            PhysicsMode::Realistic => self.turn_assist(gravity) * Vec3::new(0.0, 1.0, 1.0),
+        };
+        let trim = input.trim_delta * TRIM_RATE * dt;
+        self.pitch_trim = (self.pitch_trim + trim.x).clamp(-MAX_PITCH_TRIM, MAX_PITCH_TRIM);
+        self.yaw_trim = (self.yaw_trim + trim.y).clamp(-MAX_YAW_TRIM, MAX_YAW_TRIM);
+        self.roll_trim = (self.roll_trim + trim.z).clamp(-MAX_ROLL_TRIM, MAX_ROLL_TRIM);
+        let steering = if self.return_to_base {
+            self.return_to_base_steering()
+        } else {
+            Vec2::new(
+                apply_expo(input.yaw_left - input.yaw_right, self.expo) - self.yaw_trim + assist.y,
+                apply_expo(input.roll_right - input.roll_left, self.expo)
+                    + self.roll_trim
+                    + assist.z,
+            )
+        };
+        let yaw_input = steering.x + self.boundary_turn();
+        let pitch_input = if self.autopilot {
+            self.altitude_hold(dt)
+        } else {
+            let stick = apply_expo(input.pitch_up - input.pitch_down, self.expo);
+            let stick = if self.pitch_inverted { -stick } else { stick };
+            stick + self.pitch_trim + assist.x
+        };
+        let roll_input = steering.y;
+        self.surface_deflection =
+            Vec3::new(pitch_input, yaw_input, roll_input).clamp(-Vec3::ONE, Vec3::ONE);
+
+        let config = self.config;
+        let gear_target = if self.gear_down { 1.0 } else { 0.0 };
+        self.gear_extension += (gear_target - self.gear_extension)
+            .clamp(-dt / GEAR_TRANSIT_TIME, dt / GEAR_TRANSIT_TIME);
+        let throttle_delta = if self.auto_throttle {
+            self.speed_hold(input.throttle_delta, dt)
+        } else {
+            input.throttle_delta
+        };
+        self.throttle =
+            (self.throttle + throttle_delta * config.throttle_step * dt).clamp(IDLE_THROTTLE, 1.4);
+        let rpm_target = if self.fuel > 0.0 { self.throttle } else { 0.0 };
+        self.engine_rpm += (rpm_target - self.engine_rpm) * (1.0 - (-dt / ENGINE_SPOOL_TIME).exp());
+        self.propeller_angle = (self.propeller_angle + self.engine_rpm * PROPELLER_SPIN_RATE * dt)
+            .rem_euclid(std::f32::consts::TAU);
+
+        self.afterburner = input.boost && !self.afterburner_overheated && self.fuel > 0.0;
+        if self.afterburner {
+            self.afterburner_heat = (self.afterburner_heat + dt / AFTERBURNER_MAX_TIME).min(1.0);
+            if self.afterburner_heat >= 1.0 {
+                self.afterburner_overheated = true;
+            }
+        } else {
+            self.afterburner_heat = (self.afterburner_heat - dt / AFTERBURNER_COOLDOWN).max(0.0);
+            if self.afterburner_heat <= 0.0 {
+                self.afterburner_overheated = false;
+            }
+        }
+
+        let forward = self.forward();
+        let target_speed = if self.afterburner {
+            config.max_speed * AFTERBURNER_SPEED
+        } else {
+            config.min_speed + (config.max_speed - config.min_speed) * self.engine_rpm
+        };
+        let target_speed = match self.physics {
+            PhysicsMode::Arcade => target_speed,
+            PhysicsMode::Realistic => {
+                let bleed = forward.y * REALISTIC_CLIMB_BLEED
+                    + (self.g_force - 1.0).max(0.0) * REALISTIC_G_BLEED;
+                target_speed * (1.0 - bleed).max(0.0)
+            }
+        };
+        let density = air_density(self.position.y);
+        self.air_velocity = self.velocity - weather.wind;
+        let speed_along_forward = Vec3::dot(self.air_velocity, forward);
+        let taxiing = self.is_taxiing();
+        if speed_along_forward > config.max_speed * FLAP_LIMIT_SPEED_RATIO {
+            self.flaps = 0.0;
+        }
+        if !self.unlimited_fuel {
+            let burn = if self.afterburner {
+                AFTERBURNER_FUEL
+            } else {
+                1.0
+            };
+            self.fuel = (self.fuel - self.engine_rpm * burn * FUEL_BURN_RATE * dt).max(0.0);
+        }
+        let boost = if self.afterburner {
+            AFTERBURNER_THRUST
+        } else {
+            1.0
+        };
+        let thrust = if self.fuel <= 0.0 {
+            Vec3::ZERO
+        } else if taxiing {
+            let spool = ((self.engine_rpm - IDLE_THROTTLE) / (1.0 - IDLE_THROTTLE)).max(0.0);
+            forward * (target_speed * spool - speed_along_forward).max(0.0) * 14.0 * boost * density
+        } else {
+            forward * (target_speed - speed_along_forward) * 14.0 * boost * density
+        };
+
+        let aoa = self.angle_of_attack();
+        self.stalled = aoa.abs() > CRITICAL_AOA;
+        let flap_lift = 1.0 + FLAP_LIFT * self.flaps;
+        let stall_lift = match self.physics {
+            PhysicsMode::Arcade => STALL_LIFT_FACTOR,
+            PhysicsMode::Realistic => REALISTIC_STALL_LIFT_FACTOR,
+        };
+        let lift_coeff = if self.stalled {
+            config.lift_coeff * flap_lift * stall_lift * aoa.signum()
+        } else {
+            config.lift_coeff * flap_lift * aoa / CRITICAL_AOA
+        };
+
+        let speed = self.air_velocity.length().max(1.0);
+        let lift_dir = self.up();
+        let ground = terrain_height(self.position.x, self.position.z) + GROUND_CLEARANCE;
+        let height = (self.position.y - ground).max(0.0);
+        let ground_effect = if height < GROUND_EFFECT_CEILING {
+            1.0 + (GROUND_EFFECT_MAX - 1.0) * (1.0 - height / GROUND_EFFECT_CEILING)
+        } else {
+            1.0
+        };
+        let lift = lift_dir * speed * speed * lift_coeff * ground_effect * density;
+        self.lift_ratio = lift_coeff / config.lift_coeff;
+        let drag = -self.air_velocity
+            * speed
+            * density
+            * (config.drag_coeff + GEAR_DRAG * self.gear_extension + FLAP_DRAG * self.flaps);
+        let side_force =
+            -self.right() * Vec3::dot(self.air_velocity, self.right()) * speed * SIDESLIP_DRAG;
+        let boundary = -Vec3::new(self.position.x, 0.0, self.position.z).normalize_or_zero()
+            * self.boundary_excess()
+            * BOUNDARY_PUSH;
+        let buffet = if self.stalled {
+            Vec3::new(
+                rand::gen_range(-1.0, 1.0),
+                rand::gen_range(-1.0, 1.0),
+                rand::gen_range(-1.0, 1.0),
+            ) * STALL_BUFFET
+        } else {
+            Vec3::ZERO
+        };
+        let net_force = thrust + lift + drag + side_force + boundary + buffet + weather.gust();
+        let mass_ratio = self.mass_ratio();
+        let acceleration = net_force / mass_ratio + gravity;
+        self.forces = Forces {
+            thrust: thrust / mass_ratio,
+            lift: lift / mass_ratio,
+            drag: (drag + side_force) / mass_ratio,
+            gravity,
+        };
+
+        let previous_energy = self.energy_height(gravity);
+        self.velocity += acceleration * dt;
+        if self.is_spinning() {
+            let descent = weather.wind + Vec3::NEG_Y * SPIN_SINK_RATE;
+            self.velocity = self
+                .velocity
+                .lerp(descent, 1.0 - (-SPIN_CAPTURE_RATE * dt).exp());
+        }
+        if taxiing {
+            self.roll_on_wheels(dt, gravity);
+        }
+        self.position += self.velocity * dt;
+
+        self.vertical_speed +=
+            (self.velocity.y - self.vertical_speed) * (1.0 - (-dt / VSI_SMOOTHING).exp());
+        let lateral_g = Vec3::dot(acceleration - gravity, self.right()) / -GRAVITY.y;
+        self.lateral_g += (lateral_g - self.lateral_g) * (1.0 - (-dt / SLIP_SMOOTHING).exp());
+        if let (Some(previous), Some(energy)) = (previous_energy, self.energy_height(gravity)) {
+            let energy_rate = (energy - previous) / dt;
+            self.energy_rate +=
+                (energy_rate - self.energy_rate) * (1.0 - (-dt / ENERGY_SMOOTHING).exp());
+        } else {
+            self.energy_rate = 0.0;
+        }
+
+        let heading = self.velocity.normalize_or_zero();
+        let centripetal = acceleration - heading * Vec3::dot(acceleration, heading);
+        self.g_force = Vec3::dot(centripetal - gravity, self.up()) / -GRAVITY.y;
+        if self.g_force > G_LIMIT {
+            self.over_g_time += dt;
+            if self.over_g_time > OVER_G_BREAKUP_TIME {
+                self.crashed = true;
+            }
+        } else {
+            self.over_g_time = 0.0;
+        }
+        let excess_g = self.g_force - GLOC_ONSET;
+        self.blackout = if excess_g > 0.0 {
+            self.blackout + excess_g * GLOC_BUILD_RATE * dt
+        } else {
+            self.blackout - VISION_RECOVERY_RATE * dt
+        }
+        .clamp(0.0, 1.0);
+        let negative_g = REDOUT_ONSET - self.g_force;
+        self.redout = if negative_g > 0.0 {
+            self.redout + negative_g * REDOUT_BUILD_RATE * dt
+        } else {
+            self.redout - VISION_RECOVERY_RATE * dt
+        }
+        .clamp(0.0, 1.0);
+
+        let jitter = weather.jitter();
+        let authority = self.control_authority();
+        let nose_drop = if self.physics == PhysicsMode::Realistic && self.stalled {
+            REALISTIC_STALL_NOSE_DROP * aoa.signum()
+        } else {
+            0.0
+        };
+        let pitch_rate = pitch_input * config.pitch_rate * authority - nose_drop + jitter.x;
+        let (yaw_rate, coupling) = if taxiing {
+            (self.nosewheel_rate(yaw_input), 0.0)
+        } else {
+            let yaw_rate = yaw_input * config.yaw_rate * authority;
+            (yaw_rate, yaw_rate * YAW_ROLL_COUPLING)
+        };
+        let roll_rate = roll_input * config.roll_rate * authority - coupling + jitter.y;
+        let stick = Vec2::new(
+            input.pitch_up - input.pitch_down,
+            input.roll_right - input.roll_left,
+        );
+        self.update_spin(yaw_input, stick, input.recover, dt);
+        if self.is_spinning() {
+            let autorotation = Quat::from_rotation_y(self.spin_direction * SPIN_YAW_RATE * dt);
+            let nose_down =
+                self.heading_orientation() * Quat::from_rotation_x(SPIN_PITCH.to_radians());
+            self.orientation = (autorotation * self.orientation)
+                .slerp(nose_down, 1.0 - (-SPIN_PITCH_RATE * dt).exp())
+                .normalize();
+        } else {
+            let rotation_delta = Quat::from_axis_angle(self.right(), pitch_rate * dt)
+                * Quat::from_axis_angle(self.up(), yaw_rate * dt)
+                * Quat::from_axis_angle(self.forward(), roll_rate * dt);
+            self.orientation = (rotation_delta * self.orientation).normalize();
+        }
+        if input.recover && !self.on_ground {
+            let level = self.level_orientation();
+            self.orientation = self
+                .orientation
+                .slerp(level, 1.0 - (-RECOVER_RATE * dt).exp())
+                .normalize();
+        }
+
+        let ground = terrain_height(self.position.x, self.position.z) + GROUND_CLEARANCE;
+        if self.position.y < ground {
+            let normal = terrain_normal(self.position.x, self.position.z);
+            let impact = -Vec3::dot(self.velocity, normal);
+            let slope = normal.y.clamp(-1.0, 1.0).acos().to_degrees();
+            let hard_impact = impact > CRASH_SINK_RATE;
+            let steep_touchdown = !self.on_ground && slope > LANDING_MAX_SLOPE;
+            let bad_attitude = self.roll().abs() > CRASH_ATTITUDE || self.pitch() < -CRASH_ATTITUDE;
+            if hard_impact || steep_touchdown || bad_attitude {
+                self.crashed = true;
+            } else if !self.on_ground {
+                self.landing_score = self.touchdown_score();
+            }
+            self.on_ground = true;
+            self.position.y = ground;
+            self.velocity += normal * impact.max(0.0);
+        } else {
+            self.on_ground = false;
+        }
+    }
+
+    fn is_taxiing(&self) -> bool {
+        let ground_speed = Vec2::new(self.velocity.x, self.velocity.z).length();
+        self.on_ground && self.gear_extension >= 1.0 && ground_speed < TAXI_MAX_SPEED
+    }
+
+    fn nosewheel_rate(&self, steer: f32) -> f32 {
+        let rolling_speed = Vec3::dot(self.velocity, self.forward());
+        let angle = steer.clamp(-1.0, 1.0) * NOSEWHEEL_MAX_ANGLE.to_radians();
+        rolling_speed * angle.tan() / WHEELBASE
+    }
+
+    fn roll_on_wheels(&mut self, dt: f32, gravity: Vec3) {
+        let heading = Vec2::new(self.forward().x, self.forward().z).normalize_or_zero();
+        let rolling = Vec2::new(self.velocity.x, self.velocity.z);
+        let tracked = heading * rolling.length() * rolling.dot(heading).signum();
+        let rolling = rolling.lerp(tracked, 1.0 - (-TIRE_GRIP * dt).exp());
+        let slowed = rolling.length() - self.rolling_resistance * -gravity.y * dt;
+        let rolling = rolling.normalize_or_zero() * slowed.max(0.0);
+        self.velocity.x = rolling.x;
+        self.velocity.z = rolling.y;
+    }
+
+    fn touchdown_score(&self) -> Option<f32> {
+        let lateral = runway_offset(self.position)?;
+        if self.gear_extension < 1.0 {
+            return None;
+        }
+        let sink = -self.velocity.y;
+        let gentle = sink <= LANDING_MAX_SINK && self.velocity.length() <= LANDING_MAX_SPEED;
+        let level =
+            self.pitch().abs() <= LANDING_MAX_ATTITUDE && self.roll().abs() <= LANDING_MAX_ATTITUDE;
+        let crab = self.crab_angle().abs();
+        if !gentle || !level || crab > LANDING_MAX_CRAB {
+            return None;
+        }
+        let centered = 1.0 - lateral.abs() / (RUNWAY_WIDTH * 0.5);
+        let softness = 1.0 - sink.max(0.0) / LANDING_MAX_SINK;
+        let aligned = 1.0 - crab / LANDING_MAX_CRAB;
+        Some((centered * 40.0 + softness * 40.0 + aligned * 20.0).round())
+    }
+}
+
+#[derive(Default)]
+struct InputState {
+    roll_left: f32,
+    roll_right: f32,
+    pitch_up: f32,
+    pitch_down: f32,
+    yaw_left: f32,
+    yaw_right: f32,
+    throttle_delta: f32,
+    trim_delta: Vec3,
+    chase_height_delta: f32,
+    toggle_autopilot: bool,
+    toggle_auto_throttle: bool,
+    toggle_return_to_base: bool,
+    brake: bool,
+    fire: bool,
+    recover: bool,
+    boost: bool,
+    cockpit: bool,
+    toggle_unlimited_fuel: bool,
+    reset: bool,
+    cycle_view: bool,
+    toggle_camera_shake: bool,
+    toggle_turbulence: bool,
+    time_scrub: f32,
+    toggle_obstacles: bool,
+    toggle_horizon_clouds: bool,
+    toggle_recording: bool,
+    toggle_playback: bool,
+    toggle_pause: bool,
+    cycle_aircraft: bool,
+    toggle_gear: bool,
+    toggle_tapes: bool,
+    flaps_step: f32,
+    screenshot: bool,
+    zoom_view: bool,
+    toggle_frame_stats: bool,
+    toggle_force_vectors: bool,
+    toggle_debug_state: bool,
+    toggle_settings: bool,
+    toggle_motion_blur: bool,
+    cycle_physics: bool,
+    expo_step: f32,
+    time_scale_step: i32,
+    export_log: bool,
+    cycle_gravity: bool,
+    open_menu: bool,
+    toggle_mouse_flight: bool,
+    toggle_pitch_inversion: bool,
+    cycle_units: bool,
+    cycle_language: bool,
+    hud_scale_step: i32,
+    mouse_stick: Option<Vec2>,
+    toggle_turn_assist: bool,
+    look_delta: Vec2,
+    zoom: f32,
+}
+
+#[derive(Default)]
+struct KeyEdges {
+    down: HashSet<KeyCode>,
+    previous: HashSet<KeyCode>,
+}
+
+impl KeyEdges {
+    fn advance(&mut self, down: HashSet<KeyCode>) {
+        self.previous = std::mem::replace(&mut self.down, down);
+    }
+
+    fn poll(&mut self) {
+        let mut down = get_keys_down();
+        down.extend(get_keys_pressed());
+        self.advance(down);
+    }
+
+    fn held(&self, key: KeyCode) -> bool {
+        self.down.contains(&key)
+    }
+
+    fn just_pressed(&self, key: KeyCode) -> bool {
+        self.down.contains(&key) && !self.previous.contains(&key)
+    }
+}
+
+struct KeyBinding {
+    primary: KeyCode,
+    secondary: Option<KeyCode>,
+}
+
+impl KeyBinding {
+    fn single(primary: KeyCode) -> Self {
+        Self {
+            primary,
+            secondary: None,
+        }
+    }
+
+    fn with_alternate(primary: KeyCode, secondary: KeyCode) -> Self {
+        Self {
+            primary,
+            secondary: Some(secondary),
+        }
+    }
+
+    fn down(&self) -> bool {
+        is_key_down(self.primary) || self.secondary.is_some_and(is_key_down)
+    }
+
+    fn pressed(&self, keys: &KeyEdges) -> bool {
+        keys.just_pressed(self.primary)
+            || self
+                .secondary
+                .is_some_and(|secondary| keys.just_pressed(secondary))
+    }
+
+    fn value(&self) -> f32 {
+        if self.down() { 1.0 } else { 0.0 }
+    }
+}
+
+struct KeyBindings {
+    roll_left: KeyBinding,
+    roll_right: KeyBinding,
+    pitch_up: KeyBinding,
+    pitch_down: KeyBinding,
+    yaw_left: KeyBinding,
+    yaw_right: KeyBinding,
+    throttle_up: KeyBinding,
+    throttle_down: KeyBinding,
+    trim_up: KeyBinding,
+    trim_down: KeyBinding,
+    chase_raise: KeyBinding,
+    chase_lower: KeyBinding,
+    autopilot: KeyBinding,
+    return_to_base: KeyBinding,
+    brake: KeyBinding,
+    boost: KeyBinding,
+    cockpit: KeyBinding,
+    unlimited_fuel: KeyBinding,
+    reset: KeyBinding,
+    cycle_view: KeyBinding,
+    turbulence: KeyBinding,
+    time_forward: KeyBinding,
+    time_back: KeyBinding,
+    obstacles: KeyBinding,
+    horizon_clouds: KeyBinding,
+    record: KeyBinding,
+    playback: KeyBinding,
+    pause: KeyBinding,
+    cycle_aircraft: KeyBinding,
+    gear: KeyBinding,
+    hud_tapes: KeyBinding,
+    flaps: KeyBinding,
+    screenshot: KeyBinding,
+    zoom_view: KeyBinding,
+    frame_stats: KeyBinding,
+    force_vectors: KeyBinding,
+    settings: KeyBinding,
+    motion_blur: KeyBinding,
+    physics: KeyBinding,
+    expo: KeyBinding,
+    slower: KeyBinding,
+    faster: KeyBinding,
+    export_log: KeyBinding,
+    gravity: KeyBinding,
+    menu: KeyBinding,
+    mouse_flight: KeyBinding,
+    invert_pitch: KeyBinding,
+    units: KeyBinding,
+    recover: KeyBinding,
+    hud_smaller: KeyBinding,
+    hud_larger: KeyBinding,
+    turn_assist: KeyBinding,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            roll_left: KeyBinding::with_alternate(KeyCode::A, KeyCode::Left),
+            roll_right: KeyBinding::with_alternate(KeyCode::D, KeyCode::Right),
+            pitch_up: KeyBinding::with_alternate(KeyCode::W, KeyCode::Up),
+            pitch_down: KeyBinding::with_alternate(KeyCode::S, KeyCode::Down),
+            yaw_left: KeyBinding::single(KeyCode::Q),
+            yaw_right: KeyBinding::single(KeyCode::E),
+            throttle_up: KeyBinding::with_alternate(KeyCode::Equal, KeyCode::KpAdd),
+            throttle_down: KeyBinding::with_alternate(KeyCode::Minus, KeyCode::KpSubtract),
+            trim_up: KeyBinding::single(KeyCode::PageUp),
+            trim_down: KeyBinding::single(KeyCode::PageDown),
+            chase_raise: KeyBinding::single(KeyCode::Home),
+            chase_lower: KeyBinding::single(KeyCode::End),
+            autopilot: KeyBinding::single(KeyCode::H),
+            return_to_base: KeyBinding::single(KeyCode::L),
+            brake: KeyBinding::single(KeyCode::Space),
+            boost: KeyBinding::single(KeyCode::B),
+            cockpit: KeyBinding::single(KeyCode::C),
+            unlimited_fuel: KeyBinding::single(KeyCode::U),
+            reset: KeyBinding::single(KeyCode::R),
+            cycle_view: KeyBinding::single(KeyCode::V),
+            turbulence: KeyBinding::single(KeyCode::T),
+            time_forward: KeyBinding::single(KeyCode::RightBracket),
+            time_back: KeyBinding::single(KeyCode::LeftBracket),
+            obstacles: KeyBinding::single(KeyCode::O),
+            horizon_clouds: KeyBinding::single(KeyCode::Insert),
+            record: KeyBinding::single(KeyCode::F5),
+            playback: KeyBinding::single(KeyCode::F6),
+            pause: KeyBinding::single(KeyCode::P),
+            cycle_aircraft: KeyBinding::single(KeyCode::Tab),
+            gear: KeyBinding::single(KeyCode::G),
+            hud_tapes: KeyBinding::single(KeyCode::I),
+            flaps: KeyBinding::single(KeyCode::F),
+            screenshot: KeyBinding::single(KeyCode::F12),
+            zoom_view: KeyBinding::single(KeyCode::Z),
+            frame_stats: KeyBinding::single(KeyCode::F3),
+            force_vectors: KeyBinding::single(KeyCode::F4),
+            settings: KeyBinding::single(KeyCode::F1),
+            motion_blur: KeyBinding::single(KeyCode::F8),
+            physics: KeyBinding::single(KeyCode::F11),
+            expo: KeyBinding::single(KeyCode::F2),
+            slower: KeyBinding::single(KeyCode::Comma),
+            faster: KeyBinding::single(KeyCode::Period),
+            export_log: KeyBinding::single(KeyCode::F7),
+            gravity: KeyBinding::single(KeyCode::Y),
+            menu: KeyBinding::single(KeyCode::Escape),
+            mouse_flight: KeyBinding::single(KeyCode::M),
+            invert_pitch: KeyBinding::single(KeyCode::J),
+            units: KeyBinding::single(KeyCode::N),
+            recover: KeyBinding::single(KeyCode::X),
+            hud_smaller: KeyBinding::single(KeyCode::F9),
+            hud_larger: KeyBinding::single(KeyCode::F10),
+            turn_assist: KeyBinding::single(KeyCode::K),
+        }
+    }
+}
+
+#[derive(Default)]
+struct GamepadAxes {
+    roll: f32,
+    pitch: f32,
+    yaw: f32,
+    throttle: f32,
+}
+
+fn apply_deadzone(value: f32) -> f32 {
+    if value.abs() < STICK_DEADZONE {
+        0.0
+    } else {
+        value.signum() * (value.abs() - STICK_DEADZONE) / (1.0 - STICK_DEADZONE)
+    }
+}
+
+fn apply_expo(value: f32, expo: f32) -> f32 {
+    value * (1.0 - expo) + value.powi(3) * expo
+}
+
+impl GamepadAxes {
+    fn read(pad: Gamepad) -> Self {
+        let trigger = |button| pad.button_data(button).map_or(0.0, |data| data.value());
+        let bumper = |button| if pad.is_pressed(button) { 1.0 } else { 0.0 };
+        Self {
+            roll: apply_deadzone(pad.value(Axis::LeftStickX)),
+            pitch: apply_deadzone(-pad.value(Axis::LeftStickY)),
+            yaw: bumper(Button::LeftTrigger) - bumper(Button::RightTrigger),
+            throttle: trigger(Button::RightTrigger2) - trigger(Button::LeftTrigger2),
+        }
+    }
+}
+
+impl InputState {
+    fn gather(
+        bindings: &KeyBindings,
+        keys: &KeyEdges,
+        gamepads: Option<&mut Gilrs>,
+        mouse_flight: bool,
+    ) -> Self {
+        let pad = gamepads
+            .and_then(|gilrs| {
+                while gilrs.next_event().is_some() {}
+                gilrs
+                    .gamepads()
+                    .next()
+                    .map(|(_, pad)| GamepadAxes::read(pad))
+            })
+            .unwrap_or_default();
+        let throttle_raise = bindings.throttle_up.value();
+        let throttle_lower = bindings.throttle_down.value();
+        let shift = keys.held(KeyCode::LeftShift) || keys.held(KeyCode::RightShift);
+        let control = keys.held(KeyCode::LeftControl) || keys.held(KeyCode::RightControl);
+        let mouse_stick = mouse_flight.then(|| {
+            let center = Vec2::new(screen_width(), screen_height()) * 0.5;
+            ((Vec2::from(mouse_position()) - center) / (center.min_element() * MOUSE_FLIGHT_RANGE))
+                .clamp(-Vec2::ONE, Vec2::ONE)
+        });
+        let mouse = mouse_stick.map_or(Vec2::ZERO, |stick| {
+            Vec2::new(apply_deadzone(stick.x), -apply_deadzone(stick.y))
+        });
+        let mouse_yaw = -mouse.x * MOUSE_FLIGHT_YAW;
+        Self {
+            roll_left: bindings.roll_left.value().max(-pad.roll).max(-mouse.x),
+            roll_right: bindings.roll_right.value().max(pad.roll).max(mouse.x),
+            pitch_up: bindings.pitch_up.value().max(pad.pitch).max(mouse.y),
+            pitch_down: bindings.pitch_down.value().max(-pad.pitch).max(-mouse.y),
+            yaw_left: bindings.yaw_left.value().max(pad.yaw).max(mouse_yaw),
+            yaw_right: bindings.yaw_right.value().max(-pad.yaw).max(-mouse_yaw),
+            throttle_delta: (throttle_raise - throttle_lower + pad.throttle).clamp(-1.0, 1.0),
+            trim_delta: match (shift, control) {
+                (true, _) => Vec3::Z,
+                (false, true) => Vec3::Y,
+                (false, false) => Vec3::X,
+            } * (bindings.trim_up.value() - bindings.trim_down.value()),
+            chase_height_delta: bindings.chase_raise.value() - bindings.chase_lower.value(),
+            toggle_autopilot: bindings.autopilot.pressed(keys) && !shift,
+            toggle_auto_throttle: bindings.autopilot.pressed(keys) && shift,
+            toggle_return_to_base: bindings.return_to_base.pressed(keys),
+            brake: bindings.brake.down(),
+            fire: bindings.brake.pressed(keys),
+            recover: bindings.recover.down(),
+            boost: bindings.boost.down(),
+            cockpit: bindings.cockpit.down(),
+            toggle_unlimited_fuel: bindings.unlimited_fuel.pressed(keys),
+            reset: bindings.reset.pressed(keys),
+            cycle_view: bindings.cycle_view.pressed(keys) && !shift,
+            toggle_camera_shake: bindings.cycle_view.pressed(keys) && shift,
+            toggle_turbulence: bindings.turbulence.pressed(keys),
+            time_scrub: bindings.time_forward.value() - bindings.time_back.value(),
+            toggle_obstacles: bindings.obstacles.pressed(keys),
+            toggle_horizon_clouds: bindings.horizon_clouds.pressed(keys),
+            toggle_recording: bindings.record.pressed(keys),
+            toggle_playback: bindings.playback.pressed(keys),
+            toggle_pause: bindings.pause.pressed(keys),
+            cycle_aircraft: bindings.cycle_aircraft.pressed(keys),
+            toggle_gear: bindings.gear.pressed(keys),
+            toggle_tapes: bindings.hud_tapes.pressed(keys),
+            flaps_step: match (bindings.flaps.pressed(keys), shift) {
+                (false, _) => 0.0,
+                (true, false) => 1.0,
+                (true, true) => -1.0,
+            },
+            screenshot: bindings.screenshot.pressed(keys),
+            zoom_view: bindings.zoom_view.down(),
+            toggle_frame_stats: bindings.frame_stats.pressed(keys),
+            toggle_force_vectors: bindings.force_vectors.pressed(keys) && !shift,
+            toggle_debug_state: bindings.force_vectors.pressed(keys) && shift,
+            toggle_settings: bindings.settings.pressed(keys),
+            toggle_motion_blur: bindings.motion_blur.pressed(keys),
+            cycle_physics: bindings.physics.pressed(keys),
+            expo_step: match (bindings.expo.pressed(keys), shift) {
+                (false, _) => 0.0,
+                (true, false) => 1.0,
+                (true, true) => -1.0,
+            },
+            time_scale_step: bindings.faster.pressed(keys) as i32
+                - bindings.slower.pressed(keys) as i32,
+            export_log: bindings.export_log.pressed(keys),
+            cycle_gravity: bindings.gravity.pressed(keys),
+            open_menu: bindings.menu.pressed(keys),
+            toggle_mouse_flight: bindings.mouse_flight.pressed(keys),
+            toggle_pitch_inversion: bindings.invert_pitch.pressed(keys),
+            cycle_units: bindings.units.pressed(keys) && !shift,
+            cycle_language: bindings.units.pressed(keys) && shift,
+            hud_scale_step: bindings.hud_larger.pressed(keys) as i32
+                - bindings.hud_smaller.pressed(keys) as i32,
+            mouse_stick,
+            toggle_turn_assist: bindings.turn_assist.pressed(keys),
+            look_delta: if is_mouse_button_down(MouseButton::Left) {
+                mouse_delta_position()
+            } else {
+                Vec2::ZERO
+            },
+            zoom: mouse_wheel().1,
+        }
+    }
+}
+
+#[derive(Default)]
+struct ControlSmoothing {
+    roll: f32,
+    pitch: f32,
+    yaw: f32,
+}
+
+impl ControlSmoothing {
+    fn apply(&mut self, input: &mut InputState, dt: f32) {
+        let blend = 1.0 - (-dt / INPUT_SMOOTHING_TIME).exp();
+        let smooth = |current: &mut f32, positive: &mut f32, negative: &mut f32| {
+            *current += (*positive - *negative - *current) * blend;
+            *positive = current.max(0.0);
+            *negative = (-*current).max(0.0);
+        };
+        smooth(&mut self.roll, &mut input.roll_right, &mut input.roll_left);
+        smooth(&mut self.pitch, &mut input.pitch_up, &mut input.pitch_down);
+        smooth(&mut self.yaw, &mut input.yaw_left, &mut input.yaw_right);
+    }
+}
+
+fn initial_camera(plane: &Plane) -> Camera3D {
+    Camera3D {
+        position: Vec3::new(0.0, 120.0, 140.0),
+        target: plane.position,
+        up: Vec3::Y,
+        fovy: DEFAULT_FOV,
+        ..Default::default()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CameraMode {
+    Chase,
+    Orbit,
+    Tower,
+}
+
+struct CameraState {
+    mode: CameraMode,
+    orbit_yaw: f32,
+    orbit_pitch: f32,
+    orbit_distance: f32,
+    chase_distance: f32,
+    chase_height: f32,
+    fov_target: f32,
+    offset: Vec3,
+    target_offset: Vec3,
+    up: Vec3,
+    shake: bool,
+    jolt: f32,
+    was_on_ground: bool,
+}
+
+impl CameraState {
+    fn new(shake: bool) -> Self {
+        Self {
+            mode: CameraMode::Chase,
+            orbit_yaw: 0.0,
+            orbit_pitch: 0.35,
+            orbit_distance: 45.0,
+            chase_distance: CHASE_DISTANCE,
+            chase_height: CHASE_HEIGHT,
+            fov_target: DEFAULT_FOV,
+            offset: Vec3::new(0.0, CHASE_HEIGHT, CHASE_DISTANCE),
+            target_offset: Vec3::new(0.0, 0.0, -18.0),
+            up: Vec3::Y,
+            shake,
+            jolt: 0.0,
+            was_on_ground: false,
+        }
+    }
+
+    fn shake_offset(&mut self, plane: &Plane, weather: &Weather, cockpit: bool, dt: f32) -> Vec3 {
+        if plane.on_ground && !self.was_on_ground {
+            let sink = (-plane.vertical_speed / LANDING_MAX_SINK).max(0.0);
+            self.jolt = self.jolt.max(sink * CAMERA_TOUCHDOWN_JOLT);
+        }
+        self.was_on_ground = plane.on_ground;
+        let overstress =
+            (plane.g_force / G_LIMIT - CAMERA_OVERSTRESS_RATIO) / (1.0 - CAMERA_OVERSTRESS_RATIO);
+        if overstress > 0.0 {
+            self.jolt = self.jolt.max(overstress.min(1.0) * CAMERA_OVERSTRESS_JOLT);
+        }
+        self.jolt *= (-CAMERA_JOLT_DECAY * dt).exp();
+
+        if !self.shake || plane.crashed || dt <= 0.0 {
+            return Vec3::ZERO;
+        }
+        let speed = plane.velocity.length() / plane.config.max_speed;
+        let turbulence = if plane.on_ground {
+            0.0
+        } else {
+            weather.gust().length() / TURBULENCE_STRENGTH * speed.min(1.0)
+        };
+        let amplitude =
+            (speed * CAMERA_SHAKE_SPEED + turbulence * CAMERA_SHAKE_TURBULENCE + self.jolt)
+                * if cockpit { CAMERA_COCKPIT_SHAKE } else { 1.0 };
+        Vec3::new(
+            rand::gen_range(-1.0, 1.0),
+            rand::gen_range(-1.0, 1.0),
+            rand::gen_range(-1.0, 1.0),
+        ) * amplitude
+    }
+
+    fn cycle(&mut self) {
+        self.mode = match self.mode {
+            CameraMode::Chase => CameraMode::Orbit,
+            CameraMode::Orbit => CameraMode::Tower,
+            CameraMode::Tower => CameraMode::Chase,
+        };
+    }
+}
+
+fn update_camera(
+    camera: &mut Camera3D,
+    view: &mut CameraState,
+    plane: &Plane,
+    input: &InputState,
+    weather: &Weather,
+    paused: bool,
+    dt: f32,
+) {
+    let shake_dt = if paused { 0.0 } else { dt };
+    view.fov_target = if input.zoom_view {
+        ZOOM_FOV
+    } else {
+        DEFAULT_FOV
+    };
+    camera.fovy += (view.fov_target - camera.fovy) * (1.0 - (-FOV_LERP_RATE * dt).exp());
+
+    let forward = plane.forward();
+    let up = plane.up();
+    if input.cockpit {
+        camera.position = plane.position + forward * 1.6 + up * 0.4;
+        camera.target = plane.position + forward * 14.0 + up * 0.3;
+        camera.up = up;
+        camera.position += view.shake_offset(plane, weather, true, shake_dt);
+        return;
+    }
+
+    let (offset, target_offset, camera_up) = match view.mode {
+        CameraMode::Chase => {
+            if input.zoom != 0.0 {
+                let factor = if input.zoom > 0.0 { 0.9 } else { 1.1 };
+                view.chase_distance =
+                    (view.chase_distance * factor).clamp(CHASE_MIN_DISTANCE, CHASE_MAX_DISTANCE);
+            }
+            view.chase_height = (view.chase_height
+                + input.chase_height_delta * CHASE_HEIGHT_RATE * dt)
+                .clamp(CHASE_MIN_HEIGHT, CHASE_MAX_HEIGHT);
+            (
+                -forward * view.chase_distance + up * view.chase_height + plane.right() * 3.0,
+                forward * 18.0,
+                up,
+            )
+        }
+        CameraMode::Orbit => {
+            view.orbit_yaw += input.look_delta.x * ORBIT_SENSITIVITY;
+            view.orbit_pitch =
+                (view.orbit_pitch + input.look_delta.y * ORBIT_SENSITIVITY).clamp(-1.4, 1.4);
+            if input.zoom != 0.0 {
+                let factor = if input.zoom > 0.0 { 0.9 } else { 1.1 };
+                view.orbit_distance =
+                    (view.orbit_distance * factor).clamp(ORBIT_MIN_DISTANCE, ORBIT_MAX_DISTANCE);
+            }
+            let direction = Vec3::new(
+                view.orbit_pitch.cos() * view.orbit_yaw.sin(),
+                view.orbit_pitch.sin(),
+                view.orbit_pitch.cos() * view.orbit_yaw.cos(),
+            );
+            (direction * view.orbit_distance, Vec3::ZERO, Vec3::Y)
+        }
+        CameraMode::Tower => (TOWER_POSITION - plane.position, Vec3::ZERO, Vec3::Y),
+    };
+    let blend = if view.mode == CameraMode::Tower {
+        1.0
+    } else {
+        1.0 - (-CAMERA_STIFFNESS * dt).exp()
+    };
+    view.offset = view.offset.lerp(offset, blend);
+    view.target_offset = view.target_offset.lerp(target_offset, blend);
+    view.up = view.up.lerp(camera_up, blend).normalize_or(Vec3::Y);
+    camera.position = plane.position + view.offset;
+    camera.target = plane.position + view.target_offset;
+    camera.up = view.up;
+    camera.position += view.shake_offset(plane, weather, false, shake_dt);
+}
+
+fn draw_box(center: Vec3, axes: (Vec3, Vec3, Vec3), size: Vec3, color: Color) {
+    let (right, up, forward) = axes;
+    let offset = center - right * (size.x * 0.5) - up * (size.y * 0.5) - forward * (size.z * 0.5);
+    draw_affine_parallelepiped(
+        offset,
+        right * size.x,
+        up * size.y,
+        forward * size.z,
+        None,
+        color,
+    );
+}
+
+fn draw_cone(base: Vec3, axes: (Vec3, Vec3), tip: Vec3, radius: f32, color: Color) {
+    let (right, up) = axes;
+    let mut vertices = vec![Vertex::new(tip.x, tip.y, tip.z, 0.0, 0.0, color)];
+    let mut indices = Vec::with_capacity(ENGINE_VISUAL_SEGMENTS * 3);
+    for index in 0..ENGINE_VISUAL_SEGMENTS {
+        let angle = index as f32 / ENGINE_VISUAL_SEGMENTS as f32 * std::f32::consts::TAU;
+        let point = base + (right * angle.cos() + up * angle.sin()) * radius;
+        vertices.push(Vertex::new(point.x, point.y, point.z, 0.0, 0.0, color));
+        let next = (index + 1) % ENGINE_VISUAL_SEGMENTS;
+        indices.extend_from_slice(&[0, index as u16 + 1, next as u16 + 1]);
+    }
+    draw_mesh(&Mesh {
+        vertices,
+        indices,
+        texture: None,
+    });
+}
+
+fn draw_engine(plane: &Plane, light: f32) {
+    let forward = plane.forward();
+    let right = plane.right();
+    let up = plane.up();
+    match plane.config.engine {
+        EngineKind::Propeller => {
+            let hub = plane.position + forward * 6.1;
+            draw_sphere(hub, 0.35, None, shade(DARKGRAY, light));
+            let spin = Quat::from_axis_angle(forward, plane.propeller_angle);
+            draw_box(
+                hub,
+                (spin * right, spin * up, forward),
+                Vec3::new(PROPELLER_RADIUS * 2.0, 0.18, 0.08),
+                shade(Color::new(0.2, 0.2, 0.22, 1.0), light),
+            );
+            draw_cone(
+                hub + forward * 0.05,
+                (right, up),
+                hub + forward * 0.05,
+                PROPELLER_RADIUS,
+                Color::new(
+                    0.7,
+                    0.7,
+                    0.72,
+                    plane.engine_rpm.min(1.0) * PROPELLER_DISC_ALPHA,
+                ),
+            );
+        }
+        EngineKind::Jet => {
+            let nozzle = plane.position - forward * 3.0;
+            let pulse = 1.0 + 0.1 * (get_time() as f32 * EXHAUST_PULSE_RATE).sin();
+            let length = EXHAUST_LENGTH * plane.engine_rpm.min(1.4) * pulse;
+            draw_cone(
+                nozzle,
+                (right, up),
+                nozzle - forward * length,
+                EXHAUST_RADIUS,
+                Color::new(0.45, 0.65, 1.0, 0.2 + 0.4 * plane.engine_rpm.min(1.0)),
+            );
+        }
+    }
+}
+
+fn draw_plane_model(plane: &Plane, light: f32, opacity: f32) {
+    let forward = plane.forward();
+    let right = plane.right();
+    let up = plane.up();
+    let paint = |color: Color| {
+        let lit = shade(color, light);
+        Color::new(lit.r, lit.g, lit.b, lit.a * opacity)
+    };
+
+    draw_box(
+        plane.position + forward * 1.5,
+        (right, up, forward),
+        Vec3::new(2.2, 0.8, 9.0),
+        paint(Color::new(0.86, 0.89, 0.93, 1.0)),
+    );
+
+    draw_box(
+        plane.position,
+        (right, forward, up),
+        Vec3::new(14.0, 0.6, 0.25),
+        paint(Color::new(0.8, 0.82, 0.88, 1.0)),
+    );
+
+    draw_box(
+        plane.position + forward * -3.0 + up * -0.2,
+        (right, up, forward),
+        Vec3::new(4.5, 0.4, 3.0),
+        paint(Color::new(0.75, 0.78, 0.82, 1.0)),
+    );
+
+    draw_box(
+        plane.position + forward * 4.0 + up * 0.6,
+        (right, up, forward),
+        Vec3::new(1.1, 0.9, 1.6),
+        paint(Color::new(0.75, 0.83, 0.95, 1.0)),
+    );
+
+    draw_box(
+        plane.position + forward * -3.5 + up * 1.0,
+        (right, up, forward),
+        Vec3::new(0.2, 1.6, 1.8),
+        paint(Color::new(0.75, 0.78, 0.82, 1.0)),
+    );
+
+    let surface_color = paint(Color::new(0.62, 0.66, 0.72, 1.0));
+    let surface = |hinge: Vec3, axis: Vec3, angle: f32, size: Vec3| {
+        let rotation = Quat::from_axis_angle(axis, angle.to_radians());
+        let (r, u, f) = (rotation * right, rotation * up, rotation * forward);
+        draw_box(hinge - f * (size.z * 0.5), (r, u, f), size, surface_color);
+    };
+    let deflection = plane.surface_deflection * SURFACE_MAX_DEFLECTION;
+    for side in [-1.0, 1.0] {
+        surface(
+            plane.position + right * (5.5 * side) - forward * 0.3,
+            right,
+            side * -deflection.z,
+            Vec3::new(3.0, 0.12, 0.5),
+        );
+    }
+    surface(
+        plane.position + forward * -4.5 + up * -0.2,
+        right,
+        -deflection.x,
+        Vec3::new(4.5, 0.15, 0.8),
+    );
+    surface(
+        plane.position + forward * -4.4 + up * 1.0,
+        up,
+        -deflection.y,
+        Vec3::new(0.15, 1.5, 0.7),
+    );
+
+    if opacity >= 1.0 {
+        draw_engine(plane, light);
+    }
+
+    if plane.afterburner && opacity >= 1.0 {
+        let flicker = rand::gen_range(0.85, 1.15);
+        let nozzle = plane.position - forward * 3.4;
+        draw_sphere(nozzle, 0.6 * flicker, None, YELLOW);
+        draw_sphere(
+            nozzle - forward * 1.2,
+            1.1 * flicker,
+            None,
+            Color::new(1.0, 0.5, 0.1, 0.6),
+        );
+        draw_sphere(
+            nozzle - forward * 2.8,
+            0.8 * flicker,
+            None,
+            Color::new(1.0, 0.3, 0.05, 0.3),
+        );
+    }
+
+    if plane.gear_extension > 0.0 {
+        let length = GEAR_LENGTH * plane.gear_extension;
+        for mount in [
+            forward * 4.5,
+            forward * 0.5 + right * 1.6,
+            forward * 0.5 - right * 1.6,
+        ] {
+            let base = plane.position + mount - up * 0.4;
+            draw_box(
+                base - up * (length * 0.5),
+                (right, up, forward),
+                Vec3::new(0.25, length, 0.25),
+                paint(DARKGRAY),
+            );
+            draw_box(
+                base - up * length,
+                (right, up, forward),
+                Vec3::new(0.4, 0.6, 0.6),
+                paint(BLACK),
+            );
+        }
+    }
+}
+
+fn draw_runway() {
+    let center = Vec3::new(RUNWAY_CENTER.x, 0.05, RUNWAY_CENTER.y);
+    draw_plane(
+        center,
+        Vec2::new(RUNWAY_WIDTH * 0.5, RUNWAY_LENGTH * 0.5),
+        None,
+        Color::new(0.32, 0.33, 0.35, 1.0),
+    );
+
+    let dash_count = 14;
+    let spacing = RUNWAY_LENGTH / dash_count as f32;
+    for i in 0..dash_count {
+        let z = RUNWAY_CENTER.y - RUNWAY_LENGTH * 0.5 + spacing * (i as f32 + 0.5);
+        draw_plane(
+            Vec3::new(RUNWAY_CENTER.x, 0.1, z),
+            Vec2::new(0.6, spacing * 0.25),
+            None,
+            WHITE,
+        );
+    }
+
+    for end in [-1.0, 1.0] {
+        let z = RUNWAY_CENTER.y + end * (RUNWAY_LENGTH * 0.5 - 12.0);
+        for stripe in -3..=3 {
+            draw_plane(
+                Vec3::new(RUNWAY_CENTER.x + stripe as f32 * 4.5, 0.1, z),
+                Vec2::new(1.2, 8.0),
+                None,
+                WHITE,
+            );
+        }
+    }
+}
+
+struct MotionBlur {
+    enabled: bool,
+    poses: VecDeque<(Vec3, Quat)>,
+}
+
+impl MotionBlur {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            poses: VecDeque::with_capacity(MOTION_BLUR_GHOSTS + 1),
+        }
+    }
+
+    fn record(&mut self, plane: &Plane) {
+        if self
+            .poses
+            .front()
+            .is_some_and(|(position, _)| position.distance(plane.position) > MOTION_BLUR_MAX_STEP)
+        {
+            self.poses.clear();
+        }
+        self.poses.push_front((plane.position, plane.orientation));
+        self.poses.truncate(MOTION_BLUR_GHOSTS + 1);
+    }
+
+    fn ghosts(&self, plane: &Plane) -> Vec<(Plane, f32)> {
+        let speed = plane.velocity.length();
+        let strength = ((speed - MOTION_BLUR_MIN_SPEED)
+            / (MOTION_BLUR_FULL_SPEED - MOTION_BLUR_MIN_SPEED))
+            .clamp(0.0, 1.0);
+        if !self.enabled || plane.crashed || strength <= 0.0 {
+            return Vec::new();
+        }
+        self.poses
+            .iter()
+            .enumerate()
+            .skip(1)
+            .rev()
+            .map(|(age, &(position, orientation))| {
+                let mut ghost = plane.clone();
+                ghost.position = position;
+                ghost.orientation = orientation;
+                let fade = 1.0 - age as f32 / (MOTION_BLUR_GHOSTS + 1) as f32;
+                (ghost, MOTION_BLUR_ALPHA * strength * fade)
+            })
+            .collect()
+    }
+}
+
+struct TrailParticle {
+    position: Vec3,
+    age: f32,
+}
+
+fn update_trail(trail: &mut Vec<TrailParticle>, plane: &Plane, roll_rate: f32, dt: f32) {
+    for particle in trail.iter_mut() {
+        particle.age += dt;
+    }
+    trail.retain(|particle| particle.age < TRAIL_LIFETIME);
+
+    if plane.crashed {
+        return;
+    }
+    let intensity =
+        plane.velocity.length() / plane.config.max_speed * (1.0 + roll_rate.abs() / 90.0);
+    for side in [-1.0, 1.0] {
+        if trail.len() < MAX_TRAIL_PARTICLES && rand::gen_range(0.0, 1.0) < intensity {
+            trail.push(TrailParticle {
+                position: plane.position + plane.right() * WINGTIP_OFFSET * side,
+                age: 0.0,
+            });
+        }
+    }
+}
+
+struct VortexParticle {
+    position: Vec3,
+    right: Vec3,
+    up: Vec3,
+    side: f32,
+    strength: f32,
+    age: f32,
+}
+
+impl VortexParticle {
+    fn point(&self) -> Vec3 {
+        let angle = self.age * VORTEX_SPIN * self.side;
+        let radius = VORTEX_RADIUS * (0.3 + self.age);
+        self.position + (self.right * angle.cos() + self.up * angle.sin()) * radius
+    }
+}
+
+fn update_vortices(vortices: &mut Vec<VortexParticle>, plane: &Plane, dt: f32) {
+    for particle in vortices.iter_mut() {
+        particle.age += dt;
+    }
+    vortices.retain(|particle| particle.age < VORTEX_LIFETIME);
+
+    let strength =
+        ((plane.lift_ratio.abs() - VORTEX_THRESHOLD) / (1.0 - VORTEX_THRESHOLD)).clamp(0.0, 1.0);
+    if plane.crashed || plane.on_ground || strength <= 0.0 || dt <= 0.0 {
+        return;
+    }
+    for side in [-1.0, 1.0] {
+        if vortices.len() < MAX_VORTEX_PARTICLES {
+            vortices.push(VortexParticle {
+                position: plane.position + plane.right() * WINGTIP_OFFSET * side,
+                right: plane.right(),
+                up: plane.up(),
+                side,
+                strength,
+                age: 0.0,
+            });
+        }
+    }
+}
+
+fn draw_vortices(vortices: &[VortexParticle]) {
+    for side in [-1.0, 1.0] {
+        let mut previous: Option<&VortexParticle> = None;
+        for particle in vortices.iter().filter(|particle| particle.side == side) {
+            if let Some(last) = previous
+                && last.age - particle.age < 0.1
+            {
+                let life = 1.0 - particle.age / VORTEX_LIFETIME;
+                draw_line_3d(
+                    last.point(),
+                    particle.point(),
+                    Color::new(0.9, 0.95, 1.0, 0.5 * life * particle.strength),
+                );
+            }
+            previous = Some(particle);
+        }
+    }
+}
+
+fn draw_trail(trail: &[TrailParticle]) {
+    for particle in trail {
+        let life = 1.0 - particle.age / TRAIL_LIFETIME;
+        draw_sphere(
+            particle.position,
+            0.2 + 0.5 * life,
+            None,
+            Color::new(0.95, 0.97, 1.0, 0.45 * life),
+        );
+    }
+}
+
+struct DebrisParticle {
+    position: Vec3,
+    velocity: Vec3,
+    orientation: Quat,
+    spin: Vec3,
+    size: Vec3,
+    color: Color,
+}
+
+struct Explosion {
+    origin: Vec3,
+    debris: Vec<DebrisParticle>,
+    age: f32,
+}
+
+impl Explosion {
+    fn new(plane: &Plane) -> Self {
+        let carry = plane.velocity * DEBRIS_CARRY;
+        let debris = (0..DEBRIS_COUNT)
+            .map(|index| {
+                let direction = Vec3::new(
+                    rand::gen_range(-1.0, 1.0),
+                    rand::gen_range(0.2, 1.0),
+                    rand::gen_range(-1.0, 1.0),
+                )
+                .normalize_or_zero();
+                let color = if index % 4 == 0 {
+                    Color::new(0.25, 0.24, 0.22, 1.0)
+                } else {
+                    Color::new(0.8, 0.82, 0.88, 1.0)
+                };
+                DebrisParticle {
+                    position: plane.position,
+                    velocity: carry + direction * DEBRIS_SPEED * rand::gen_range(0.3, 1.0),
+                    orientation: plane.orientation,
+                    spin: Vec3::new(
+                        rand::gen_range(-1.0, 1.0),
+                        rand::gen_range(-1.0, 1.0),
+                        rand::gen_range(-1.0, 1.0),
+                    ) * DEBRIS_SPIN,
+                    size: Vec3::new(
+                        rand::gen_range(0.3, 2.0),
+                        rand::gen_range(0.1, 0.5),
+                        rand::gen_range(0.3, 2.5),
+                    ),
+                    color,
+                }
+            })
+            .collect();
+        Self {
+            origin: plane.position,
+            debris,
+            age: 0.0,
+        }
+    }
+
+    fn update(&mut self, gravity: Vec3, dt: f32) {
+        self.age += dt;
+        for particle in &mut self.debris {
+            particle.velocity += gravity * dt;
+            particle.position += particle.velocity * dt;
+            particle.orientation =
+                (Quat::from_scaled_axis(particle.spin * dt) * particle.orientation).normalize();
+            let ground = terrain_height(particle.position.x, particle.position.z) + 0.2;
+            if particle.position.y < ground {
+                particle.position.y = ground;
+                particle.velocity = Vec3::ZERO;
+                particle.spin = Vec3::ZERO;
+            }
+        }
+    }
+
+    fn draw(&self, light: f32) {
+        let life = (1.0 - self.age / EXPLOSION_LIFETIME).clamp(0.0, 1.0);
+        if life <= 0.0 {
+            return;
+        }
+        for particle in &self.debris {
+            let axes = (
+                particle.orientation * Vec3::X,
+                particle.orientation * Vec3::Y,
+                particle.orientation * Vec3::Z,
+            );
+            let lit = shade(particle.color, light);
+            draw_box(
+                particle.position,
+                axes,
+                particle.size,
+                Color::new(lit.r, lit.g, lit.b, life),
+            );
+        }
+
+        let spread = self.age / EXPLOSION_LIFETIME;
+        draw_sphere(
+            self.origin + Vec3::Y * (spread * EXPLOSION_SMOKE_RADIUS),
+            EXPLOSION_SMOKE_RADIUS * (0.3 + spread),
+            None,
+            Color::new(0.2, 0.2, 0.2, 0.5 * life),
+        );
+        if self.age < EXPLOSION_FLASH_TIME {
+            let flash = self.age / EXPLOSION_FLASH_TIME;
+            draw_sphere(
+                self.origin,
+                EXPLOSION_FLASH_RADIUS * (0.4 + flash),
+                None,
+                Color::new(1.0, 0.75 - 0.35 * flash, 0.2, 0.85 * (1.0 - flash)),
+            );
+        }
+    }
+}
+
+struct SkyCamera<'a>(&'a Camera3D);
+
+impl Camera for SkyCamera<'_> {
+    fn matrix(&self) -> Mat4 {
+        self.0.matrix()
+    }
+
+    fn depth_enabled(&self) -> bool {
+        false
+    }
+
+    fn render_pass(&self) -> Option<RenderPass> {
+        self.0.render_pass()
+    }
+
+    fn viewport(&self) -> Option<(i32, i32, i32, i32)> {
+        self.0.viewport()
+    }
+}
+
+fn draw_sky(camera: &Camera3D, time_of_day: &TimeOfDay) {
+    let horizon = time_of_day.horizon_color();
+    let zenith = time_of_day.zenith_color();
+    clear_background(horizon);
+    set_camera(&SkyCamera(camera));
+
+    let mut vertices = Vec::with_capacity((SKY_RINGS + 1) * (SKY_SEGMENTS + 1));
+    for ring in 0..=SKY_RINGS {
+        let elevation = (ring as f32 / SKY_RINGS as f32 * 110.0 - 20.0).to_radians();
+        let t = elevation.sin().max(0.0).sqrt();
+        let color = Color::new(
+            horizon.r + (zenith.r - horizon.r) * t,
+            horizon.g + (zenith.g - horizon.g) * t,
+            horizon.b + (zenith.b - horizon.b) * t,
+            1.0,
+        );
+        for segment in 0..=SKY_SEGMENTS {
+            let azimuth = segment as f32 / SKY_SEGMENTS as f32 * std::f32::consts::TAU;
+            let point = camera.position
+                + Vec3::new(
+                    elevation.cos() * azimuth.sin(),
+                    elevation.sin(),
+                    elevation.cos() * azimuth.cos(),
+                ) * SKY_RADIUS;
+            vertices.push(Vertex::new(point.x, point.y, point.z, 0.0, 0.0, color));
+        }
+    }
+    let stride = SKY_SEGMENTS as u16 + 1;
+    let mut indices = Vec::with_capacity(SKY_RINGS * SKY_SEGMENTS * 6);
+    for ring in 0..SKY_RINGS as u16 {
+        for segment in 0..SKY_SEGMENTS as u16 {
+            let a = ring * stride + segment;
+            let b = a + stride;
+            indices.extend_from_slice(&[a, b, a + 1, a + 1, b, b + 1]);
+        }
+    }
+    draw_mesh(&Mesh {
+        vertices,
+        indices,
+        texture: None,
+    });
+
+    let sun = time_of_day.sun_direction();
+    if sun.y > -0.1 {
+        let center = camera.position + sun * SKY_RADIUS * 0.9;
+        let warmth = sun.y.clamp(0.0, 0.4) / 0.4;
+        draw_sphere(
+            center,
+            SUN_SIZE * 2.2,
+            None,
+            Color::new(1.0, 0.85, 0.6, 0.15),
+        );
+        draw_sphere(
+            center,
+            SUN_SIZE,
+            None,
+            Color::new(1.0, 0.7 + 0.28 * warmth, 0.4 + 0.5 * warmth, 1.0),
+        );
+    }
+}
+
+fn altitude_levels() -> impl Iterator<Item = f32> {
+    (1..=ALTITUDE_MARKER_LEVELS).map(|level| level as f32 * ALTITUDE_MARKER_STEP)
+}
+
+fn draw_altitude_grids(eye: Vec3, light: f32) {
+    let center = (Vec2::new(eye.x, eye.z) / ALTITUDE_GRID_SPACING).round() * ALTITUDE_GRID_SPACING;
+    let lines = (ALTITUDE_GRID_EXTENT / ALTITUDE_GRID_SPACING) as i32;
+    for height in altitude_levels() {
+        let fade = 1.0 - (height - eye.y).abs() / ALTITUDE_MARKER_FADE;
+        if fade <= 0.0 {
+            continue;
+        }
+        for i in -lines..=lines {
+            let offset = i as f32 * ALTITUDE_GRID_SPACING;
+            let edge = 1.0 - offset.abs() / ALTITUDE_GRID_EXTENT;
+            let color = Color::new(0.85, 0.9, 1.0, 0.18 * fade * edge * light);
+            draw_line_3d(
+                Vec3::new(center.x + offset, height, center.y - ALTITUDE_GRID_EXTENT),
+                Vec3::new(center.x + offset, height, center.y + ALTITUDE_GRID_EXTENT),
+                color,
+            );
+            draw_line_3d(
+                Vec3::new(center.x - ALTITUDE_GRID_EXTENT, height, center.y + offset),
+                Vec3::new(center.x + ALTITUDE_GRID_EXTENT, height, center.y + offset),
+                color,
+            );
+        }
+    }
+}
+
+fn world_to_screen(camera: &Camera3D, point: Vec3, screen: Vec2) -> Option<Vec2> {
+    let clip = camera.matrix() * point.extend(1.0);
+    if clip.w <= 0.0 {
+        return None;
+    }
+    let ndc = clip.xy() / clip.w;
+    (ndc.abs().cmple(Vec2::ONE).all()).then(|| {
+        Vec2::new(
+            (ndc.x + 1.0) * 0.5 * screen.x,
+            (1.0 - ndc.y) * 0.5 * screen.y,
+        )
+    })
+}
+
+fn draw_altitude_labels(screen: Vec2, camera: &Camera3D, units: UnitSystem) {
+    let look = camera.target - camera.position;
+    let ahead = Vec2::new(look.x, look.z).normalize_or(Vec2::NEG_Y) * ALTITUDE_GRID_EXTENT * 0.5;
+    for height in altitude_levels() {
+        let point = Vec3::new(
+            camera.position.x + ahead.x,
+            height,
+            camera.position.z + ahead.y,
+        );
+        let fade = 1.0 - point.distance(camera.position) / ALTITUDE_MARKER_FADE;
+        if fade <= 0.0 {
+            continue;
+        }
+        if let Some(screen) = world_to_screen(camera, point, screen) {
+            let label = format!("{:.0} {}", units.altitude(height), units.altitude_unit());
+            let dims = measure_text(&label, None, 24, 1.0);
+            draw_text(
+                &label,
+                screen.x - dims.width * 0.5,
+                screen.y,
+                24.0,
+                Color::new(0.85, 0.9, 1.0, 0.8 * fade),
+            );
+        }
+    }
+}
+
+fn draw_rings(rings: &RingRace, light: f32) {
+    for (index, gate) in rings.gates.iter().enumerate() {
+        let color = if index == rings.next {
+            ORANGE
+        } else if index < rings.next {
+            Color::new(0.3, 0.9, 0.4, 0.35)
+        } else {
+            Color::new(0.9, 0.9, 1.0, 0.6)
+        };
+        let color = shade(color, light);
+        let (side, up) = gate.axes();
+        let mut vertices = Vec::with_capacity(RING_SEGMENTS * 2);
+        let mut indices = Vec::with_capacity(RING_SEGMENTS * 6);
+        for segment in 0..RING_SEGMENTS {
+            let angle = segment as f32 / RING_SEGMENTS as f32 * std::f32::consts::TAU;
+            let radial = side * angle.cos() + up * angle.sin();
+            for radius in [RING_RADIUS - RING_THICKNESS, RING_RADIUS + RING_THICKNESS] {
+                let point = gate.center + radial * radius;
+                vertices.push(Vertex::new(point.x, point.y, point.z, 0.0, 0.0, color));
+            }
+            let base = (segment * 2) as u16;
+            let next = (((segment + 1) % RING_SEGMENTS) * 2) as u16;
+            indices.extend_from_slice(&[base, base + 1, next + 1, base, next + 1, next]);
+        }
+        draw_mesh(&Mesh {
+            vertices,
+            indices,
+            texture: None,
+        });
+    }
+}
+
+fn force_vectors(plane: &Plane, language: Language) -> [(&'static str, Vec3, f32, Color); 5] {
+    let forces = plane.forces;
+    [
+        (
+            language.text("Hız", "Velocity"),
+            plane.velocity,
+            VELOCITY_VECTOR_SCALE,
+            WHITE,
+        ),
+        (
+            language.text("İtki", "Thrust"),
+            forces.thrust,
+            FORCE_VECTOR_SCALE,
+            ORANGE,
+        ),
+        (
+            language.text("Taşıma", "Lift"),
+            forces.lift,
+            FORCE_VECTOR_SCALE,
+            GREEN,
+        ),
+        (
+            language.text("Sürükleme", "Drag"),
+            forces.drag,
+            FORCE_VECTOR_SCALE,
+            RED,
+        ),
+        (
+            language.text("Yerçekimi", "Gravity"),
+            forces.gravity,
+            FORCE_VECTOR_SCALE,
+            VIOLET,
+        ),
+    ]
+}
+
+fn draw_force_vectors(plane: &Plane, language: Language) {
+    for (_, vector, scale, color) in force_vectors(plane, language) {
+        let tip = plane.position + vector * scale;
+        draw_line_3d(plane.position, tip, color);
+        draw_sphere(tip, 0.3, None, color);
+    }
+}
+
+fn draw_force_legend(screen: Vec2, plane: &Plane, language: Language) {
+    for (index, (label, vector, _, color)) in force_vectors(plane, language).into_iter().enumerate()
+    {
+        let unit = if index == 0 { "m/s" } else { "m/s²" };
+        draw_text(
+            format!("{}: {:.1} {}", label, vector.length(), unit),
+            screen.x - 290.0,
+            172.0 + index as f32 * 24.0,
+            22.0,
+            color,
+        );
+    }
+}
+
+fn draw_debug_state(screen: Vec2, plane: &Plane) {
+    let q = plane.orientation;
+    let (yaw, pitch, roll) = q.to_euler(EulerRot::YXZ);
+    let v = plane.velocity;
+    let lines = [
+        format!(
+            "q: {:+.3} {:+.3} {:+.3} {:+.3} |{:.4}|",
+            q.x,
+            q.y,
+            q.z,
+            q.w,
+            q.length()
+        ),
+        format!(
+            "Euler YXZ: {:+.1} {:+.1} {:+.1}",
+            yaw.to_degrees(),
+            pitch.to_degrees(),
+            roll.to_degrees()
+        ),
+        format!(
+            "Pitch {:+.1} Roll {:+.1} Hdg {:.1}",
+            plane.pitch(),
+            plane.roll(),
+            plane.heading()
+        ),
+        format!("v: {:+.1} {:+.1} {:+.1} m/s", v.x, v.y, v.z),
+        format!("Gaz: {:.2} RPM: {:.2}", plane.throttle, plane.engine_rpm),
+    ];
+    for (index, line) in lines.iter().enumerate() {
+        draw_text(
+            line,
+            screen.x - 290.0,
+            300.0 + index as f32 * 22.0,
+            20.0,
+            SKYBLUE,
+        );
+    }
+}
+
+fn draw_settings_panel(screen: Vec2, plane: &Plane, settings: &HudSettings) {
+    let language = settings.language;
+    let lines = [
+        format!(
+            "{}: {} (F11)",
+            language.text("Fizik", "Physics"),
+            plane.physics.label(language)
+        ),
+        format!("Expo: {:.1} (F2/Shift+F2)", plane.expo),
+        format!(
+            "{}: {:.0}% (F9/F10)",
+            language.text("HUD ölçeği", "HUD scale"),
+            settings.user_scale * 100.0
+        ),
+        format!(
+            "{}: {:.2} s",
+            language.text("Gösterge yumuşatma", "Readout smoothing"),
+            settings.smoothing
+        ),
+        format!(
+            "{}: {} (N)",
+            language.text("Birimler", "Units"),
+            settings.units.label(language)
+        ),
+        format!(
+            "{}: {} (Shift+N)",
+            language.text("Dil", "Language"),
+            language.label()
+        ),
+        format!(
+            "{}: {} (J)",
+            language.text("Ters pitch", "Inverted pitch"),
+            language.on_off(plane.pitch_inverted)
+        ),
+        format!(
+            "{}: {:.2}",
+            language.text("Zemin sürtünmesi", "Ground friction"),
+            plane.rolling_resistance
+        ),
+        format!(
+            "GPWS: {:.0} s / {:.0} m",
+            plane.gpws_time, plane.gpws_height
+        ),
+    ];
+    let column = 420.0;
+    let controls_width = CONTROLS_COLUMN_WIDTH
+        .min(screen.x - column - 60.0)
+        .max(200.0);
+    let controls = wrap_entries(
+        language.text(CONTROLS_HINT, CONTROLS_HINT_EN),
+        controls_width,
+        |line| measure_text(line, None, 18, 1.0).width,
+    );
+    let width = column + controls_width + 20.0;
+    let height = (64.0 + lines.len() as f32 * 28.0 + EXPO_CURVE_SIZE + 24.0)
+        .max(64.0 + controls.len() as f32 * 22.0 + 12.0);
+    let left = screen.x * 0.5 - width * 0.5;
+    let top = (screen.y * 0.5 - height * 0.5).max(0.0);
+    draw_rectangle(left, top, width, height, Color::new(0.0, 0.0, 0.0, 0.75));
+    draw_rectangle_lines(left, top, width, height, 2.0, LIGHTGRAY);
+    draw_text(
+        language.text("Ayarlar (F1)", "Settings (F1)"),
+        left + 20.0,
+        top + 36.0,
+        30.0,
+        WHITE,
+    );
+    for (index, line) in lines.iter().enumerate() {
+        draw_text(
+            line,
+            left + 20.0,
+            top + 72.0 + index as f32 * 28.0,
+            24.0,
+            LIGHTGRAY,
+        );
+    }
+    draw_text(
+        language.text("Kontroller", "Controls"),
+        left + column,
+        top + 36.0,
+        30.0,
+        WHITE,
+    );
+    for (index, line) in controls.iter().enumerate() {
+        draw_text(
+            line,
+            left + column,
+            top + 72.0 + index as f32 * 22.0,
+            18.0,
+            LIGHTGRAY,
+        );
+    }
+    let origin = Vec2::new(
+        left + column * 0.5,
+        top + 72.0 + lines.len() as f32 * 28.0 + EXPO_CURVE_SIZE * 0.5,
+    );
+    let half = EXPO_CURVE_SIZE * 0.5;
+    draw_rectangle_lines(
+        origin.x - half,
+        origin.y - half,
+        EXPO_CURVE_SIZE,
+        EXPO_CURVE_SIZE,
+        1.0,
+        GRAY,
+    );
+    draw_line(
+        origin.x - half,
+        origin.y + half,
+        origin.x + half,
+        origin.y - half,
+        1.0,
+        GRAY,
+    );
+    let point = |input: f32| origin + Vec2::new(input, -apply_expo(input, plane.expo)) * half;
+    for step in 0..40 {
+        let from = point(step as f32 / 20.0 - 1.0);
+        let to = point((step + 1) as f32 / 20.0 - 1.0);
+        draw_line(from.x, from.y, to.x, to.y, 2.0, GREEN);
+    }
+}
+
+fn draw_shadow(plane: &Plane) {
+    let ground = terrain_height(plane.position.x, plane.position.z);
+    let height = plane.position.y - ground;
+    let fade = 1.0 - height / SHADOW_FADE_HEIGHT;
+    if fade <= 0.0 {
+        return;
+    }
+    let flatten = |v: Vec3| Vec2::new(v.x, v.z);
+    let center = flatten(plane.position);
+    let right = flatten(plane.right());
+    let forward = flatten(plane.forward());
+    let spread = 1.0 + (1.0 - fade) * 0.5;
+    let color = Color::new(0.0, 0.0, 0.0, 0.45 * fade);
+    let parts = [
+        (1.5, Vec2::new(1.1, 4.5)),
+        (0.0, Vec2::new(7.0, 0.3)),
+        (-3.0, Vec2::new(2.25, 1.5)),
+    ];
+    let mut vertices = Vec::with_capacity(parts.len() * 4);
+    let mut indices = Vec::with_capacity(parts.len() * 6);
+    for (along, half) in parts {
+        let base = vertices.len() as u16;
+        for (x, z) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)] {
+            let point =
+                center + (forward * along + right * (half.x * x) + forward * (half.y * z)) * spread;
+            let y = terrain_height(point.x, point.y) + 0.15;
+            vertices.push(Vertex::new(point.x, y, point.y, 0.0, 0.0, color));
+        }
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+    draw_mesh(&Mesh {
+        vertices,
+        indices,
+        texture: None,
+    });
+}
+
+struct SceneEffects<'a> {
+    ghosts: &'a [(Plane, f32)],
+    trail: &'a [TrailParticle],
+    vortices: &'a [VortexParticle],
+    explosion: Option<&'a Explosion>,
+}
+
+fn draw_environment(
+    plane: &Plane,
+    traffic: &[Plane],
+    effects: &SceneEffects,
+    world: &World,
+    eye: Vec3,
+) {
+    let sky = world.time_of_day.horizon_color();
+    let light = world.time_of_day.ambient();
+
+    world.terrain.draw();
+    if world.horizon_layer {
+        draw_horizon_clouds(&world.horizon_clouds, eye, light, sky);
+    }
+
+    draw_grid(
+        80,
+        40.0,
+        Color::new(0.3, 0.35, 0.3, 0.3 * light),
+        Color::new(0.2, 0.25, 0.2, 0.2 * light),
+    );
+    draw_altitude_grids(eye, light);
+
+    draw_runway();
+    draw_cube(
+        Vec3::new(
+            TOWER_POSITION.x,
+            (TOWER_POSITION.y - 3.0) * 0.5,
+            TOWER_POSITION.z,
+        ),
+        Vec3::new(6.0, TOWER_POSITION.y - 3.0, 6.0),
+        None,
+        shade(Color::new(0.7, 0.7, 0.68, 1.0), light),
+    );
+
+    for (index, waypoint) in world.route.waypoints.iter().enumerate() {
+        let color = if index == world.route.active {
+            ORANGE
+        } else {
+            Color::new(0.8, 0.8, 0.8, 0.6)
+        };
+        let base = terrain_height(waypoint.x, waypoint.z);
+        draw_cube(
+            Vec3::new(waypoint.x, (waypoint.y + base) * 0.5, waypoint.z),
+            Vec3::new(3.0, waypoint.y - base, 3.0),
+            None,
+            shade(color, light),
+        );
+        draw_sphere(*waypoint, 8.0, None, color);
+    }
+
+    if world.scenario == Scenario::RingRace {
+        draw_rings(&world.rings, light);
+    }
+
+    for other in traffic {
+        draw_shadow(other);
+    }
+    if !plane.crashed {
+        draw_shadow(plane);
+    }
+    for projectile in &world.projectiles {
+        draw_line_3d(
+            projectile.position,
+            projectile.position - projectile.velocity.normalize_or_zero() * PROJECTILE_STREAK,
+            ORANGE,
+        );
+        draw_sphere(projectile.position, PROJECTILE_RADIUS, None, YELLOW);
+    }
+    let sun = world.time_of_day.sun_direction();
+    let model_light = |plane: &Plane| light * (0.7 + 0.3 * plane.up().dot(sun).max(0.0));
+    for other in traffic {
+        draw_plane_model(other, model_light(other), 1.0);
+    }
+    if !plane.crashed {
+        draw_plane_model(plane, model_light(plane), 1.0);
+    }
+
+    let mut clouds: Vec<(f32, &Cloud)> = world
+        .clouds
+        .iter()
+        .filter(|cloud| !cloud.popped)
+        .map(|cloud| (cloud.center.distance_squared(eye), cloud))
+        .collect();
+    clouds.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (distance, cloud) in clouds {
+        let color = fog(shade(cloud.color, light), sky, distance.sqrt());
+        draw_cube(cloud.center, cloud.size, None, color);
+    }
+    for (ghost, opacity) in effects.ghosts {
+        draw_plane_model(ghost, model_light(ghost), *opacity);
+    }
+    draw_trail(effects.trail);
+    draw_vortices(effects.vortices);
+    if let Some(explosion) = effects.explosion {
+        explosion.draw(light);
+    }
+}
+
+fn draw_attitude_indicator(center: Vec2, pitch: f32, roll: f32) {
+    let rotation = Mat2::from_angle(roll.to_radians());
+    let to_screen = |local: Vec2| center + rotation * local;
+
+    draw_circle(
+        center.x,
+        center.y,
+        ATTITUDE_RADIUS,
+        Color::new(0.22, 0.52, 0.86, 1.0),
+    );
+
+    let offset = (pitch * ATTITUDE_PX_PER_DEG).clamp(-ATTITUDE_RADIUS, ATTITUDE_RADIUS);
+    let start = (offset / ATTITUDE_RADIUS).asin();
+    let end = std::f32::consts::PI - start;
+    let steps = 32;
+    let ground: Vec<Vec2> = (0..=steps)
+        .map(|i| {
+            let angle = start + (end - start) * i as f32 / steps as f32;
+            to_screen(Vec2::new(angle.cos(), angle.sin()) * ATTITUDE_RADIUS)
+        })
+        .collect();
+    for i in 1..ground.len() - 1 {
+        draw_triangle(
+            ground[0],
+            ground[i],
+            ground[i + 1],
+            Color::new(0.52, 0.34, 0.18, 1.0),
+        );
+    }
+    let (left, right) = (ground[ground.len() - 1], ground[0]);
+    draw_line(left.x, left.y, right.x, right.y, 2.0, WHITE);
+
+    draw_line(center.x - 40.0, center.y, center.x - 12.0, center.y, 3.0, YELLOW);
+    draw_line(center.x + 12.0, center.y, center.x + 40.0, center.y, 3.0, YELLOW);
+    draw_circle(center.x, center.y, 3.0, YELLOW);
+    draw_circle_lines(center.x, center.y, ATTITUDE_RADIUS, 3.0, LIGHTGRAY);
+}
+
+struct SimClock {
+    paused: bool,
+    time_scale_index: usize,
+    accumulator: f32,
+    elapsed: f32,
+}
+
+impl SimClock {
+    fn new() -> Self {
+        Self {
+            paused: false,
+            time_scale_index: DEFAULT_TIME_SCALE,
+            accumulator: 0.0,
+            elapsed: 0.0,
+        }
+    }
+
+    fn time_scale(&self) -> f32 {
+        TIME_SCALES[self.time_scale_index]
+    }
+
+    fn step_time_scale(&mut self, direction: i32) {
+        self.time_scale_index = self
+            .time_scale_index
+            .saturating_add_signed(direction as isize)
+            .min(TIME_SCALES.len() - 1);
+    }
+}
+
+struct ScenarioMenu {
+    selected: usize,
+}
+
+impl ScenarioMenu {
+    fn new() -> Self {
+        Self { selected: 0 }
+    }
+
+    fn item_rect(index: usize) -> Rect {
+        Rect::new(
+            screen_width() * 0.5 - MENU_ITEM_WIDTH * 0.5,
+            screen_height() * 0.35 + index as f32 * MENU_ITEM_HEIGHT,
+            MENU_ITEM_WIDTH,
+            MENU_ITEM_HEIGHT - 8.0,
+        )
+    }
+
+    fn update(&mut self, keys: &KeyEdges) -> Option<Scenario> {
+        if keys.just_pressed(KeyCode::Down) || keys.just_pressed(KeyCode::S) {
+            self.selected = (self.selected + 1) % SCENARIOS.len();
+        }
+        if keys.just_pressed(KeyCode::Up) || keys.just_pressed(KeyCode::W) {
+            self.selected = (self.selected + SCENARIOS.len() - 1) % SCENARIOS.len();
+        }
+        let number_keys = [
+            KeyCode::Key1,
+            KeyCode::Key2,
+            KeyCode::Key3,
+            KeyCode::Key4,
+            KeyCode::Key5,
+            KeyCode::Key6,
+            KeyCode::Key7,
+            KeyCode::Key8,
+        ];
+        if let Some(index) = number_keys.iter().position(|&key| keys.just_pressed(key)) {
+            self.selected = index;
+            return Some(SCENARIOS[index]);
+        }
+        let mouse = Vec2::from(mouse_position());
+        if let Some(index) =
+            (0..SCENARIOS.len()).find(|&index| Self::item_rect(index).contains(mouse))
+        {
+            self.selected = index;
+            if is_mouse_button_pressed(MouseButton::Left) {
+                return Some(SCENARIOS[index]);
+            }
+        }
+        (keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::KpEnter))
+            .then_some(SCENARIOS[self.selected])
+    }
+
+    fn draw(&self, language: Language) {
+        set_default_camera();
+        clear_background(Color::new(0.05, 0.08, 0.14, 1.0));
+        let title = language.text("Senaryo Seçin", "Choose a Scenario");
+        let dims = measure_text(title, None, 56, 1.0);
+        draw_text(
+            title,
+            screen_width() * 0.5 - dims.width * 0.5,
+            screen_height() * 0.25,
+            56.0,
+            WHITE,
+        );
+        for (index, scenario) in SCENARIOS.iter().enumerate() {
+            let rect = Self::item_rect(index);
+            let selected = index == self.selected;
+            draw_rectangle(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                if selected {
+                    Color::new(0.2, 0.45, 0.8, 0.9)
+                } else {
+                    Color::new(0.15, 0.18, 0.25, 0.9)
+                },
+            );
+            draw_text(
+                format!("{}. {}", index + 1, scenario.label(language)),
+                rect.x + 20.0,
+                rect.y + rect.h * 0.5 + 10.0,
+                32.0,
+                if selected { WHITE } else { LIGHTGRAY },
+            );
+        }
+        let hint = language.text(
+            "Yukarı/Aşağı veya 1-8 ile seçin, Enter ya da fare ile başlayın | Esc: menüye dön",
+            "Select with Up/Down or 1-8, start with Enter or the mouse | Esc: back to menu",
+        );
+        let dims = measure_text(hint, None, 24, 1.0);
+        draw_text(
+            hint,
+            screen_width() * 0.5 - dims.width * 0.5,
+            screen_height() * 0.35 + SCENARIOS.len() as f32 * MENU_ITEM_HEIGHT + 40.0,
+            24.0,
+            LIGHTGRAY,
+        );
+    }
+}
+
+#[derive(Default)]
+struct HudReadouts {
+    primed: bool,
+    speed: f32,
+    indicated: f32,
+    altitude: f32,
+    g_force: f32,
+}
+
+impl HudReadouts {
+    fn track(&mut self, plane: &Plane, dt: f32, smoothing: f32) {
+        let blend = if !self.primed || smoothing <= 0.0 {
+            1.0
+        } else {
+            1.0 - (-dt / smoothing).exp()
+        };
+        self.primed = true;
+        self.speed += (plane.velocity.length() - self.speed) * blend;
+        self.indicated += (plane.indicated_airspeed() - self.indicated) * blend;
+        self.altitude += (plane.position.y.max(0.0) - self.altitude) * blend;
+        self.g_force += (plane.g_force - self.g_force) * blend;
+    }
+}
+
+struct HudSettings {
+    tapes: bool,
+    units: UnitSystem,
+    language: Language,
+    user_scale: f32,
+    smoothing: f32,
+    readouts: HudReadouts,
+    frame_stats: bool,
+    force_vectors: bool,
+    debug_state: bool,
+    panel: bool,
+    notice: String,
+    notice_timer: f32,
+}
+
+impl HudSettings {
+    fn new(units: UnitSystem, language: Language, user_scale: f32, smoothing: f32) -> Self {
+        Self {
+            tapes: true,
+            units,
+            language,
+            user_scale,
+            smoothing,
+            readouts: HudReadouts::default(),
+            frame_stats: false,
+            force_vectors: false,
+            debug_state: false,
+            panel: false,
+            notice: String::new(),
+            notice_timer: 0.0,
+        }
+    }
+
+    fn scale(&self) -> f32 {
+        let fit = (screen_height() / HUD_REFERENCE_HEIGHT).clamp(HUD_MIN_FIT, HUD_MAX_FIT);
+        fit * self.user_scale
+    }
+
+    fn step_scale(&mut self, step: i32) {
+        self.user_scale =
+            (self.user_scale + step as f32 * HUD_SCALE_STEP).clamp(HUD_MIN_SCALE, HUD_MAX_SCALE);
+        self.notify(format!(
+            "{}: {:.0}%",
+            self.language.text("HUD ölçeği", "HUD scale"),
+            self.user_scale * 100.0
+        ));
+    }
+
+    fn notify(&mut self, notice: String) {
+        self.notice = notice;
+        self.notice_timer = NOTICE_DURATION;
+    }
+}
+
+struct FrameStats {
+    samples: [f32; FRAME_SAMPLES],
+    next: usize,
+    filled: usize,
+}
+
+impl FrameStats {
+    fn new() -> Self {
+        Self {
+            samples: [0.0; FRAME_SAMPLES],
+            next: 0,
+            filled: 0,
+        }
+    }
+
+    fn record(&mut self, frame_time: f32) {
+        self.samples[self.next] = frame_time;
+        self.next = (self.next + 1) % FRAME_SAMPLES;
+        self.filled = (self.filled + 1).min(FRAME_SAMPLES);
+    }
+
+    fn average(&self) -> f32 {
+        if self.filled == 0 {
+            return 0.0;
+        }
+        self.samples[..self.filled].iter().sum::<f32>() / self.filled as f32
+    }
+}
+
+fn draw_frame_stats(stats: &FrameStats) {
+    let frame_time = stats.average();
+    let fps = if frame_time > 0.0 {
+        1.0 / frame_time
+    } else {
+        0.0
+    };
+    let text = format!("{:.0} FPS  {:.2} ms", fps, frame_time * 1000.0);
+    let dims = measure_text(&text, None, 24, 1.0);
+    draw_text(
+        text,
+        screen_width() - dims.width - 24.0,
+        64.0,
+        24.0,
+        LIGHTGRAY,
+    );
+}
+
+fn draw_tape(screen: Vec2, left: f32, value: f32, range: f32, step: f32, ticks_on_right: bool) {
+    let top = screen.y * 0.5 - TAPE_HEIGHT * 0.5;
+    let center_y = top + TAPE_HEIGHT * 0.5;
+    let px_per_unit = TAPE_HEIGHT / (range * 2.0);
+    draw_rectangle(
+        left,
+        top,
+        TAPE_WIDTH,
+        TAPE_HEIGHT,
+        Color::new(0.0, 0.0, 0.0, 0.45),
+    );
+
+    let first = ((value - range) / step).ceil().max(0.0) as i32;
+    let last = ((value + range) / step).floor() as i32;
+    for index in first..=last {
+        let mark = index as f32 * step;
+        let y = center_y - (mark - value) * px_per_unit;
+        let major = index % 2 == 0;
+        let tick = if major { 14.0 } else { 7.0 };
+        let (x0, x1) = if ticks_on_right {
+            (left + TAPE_WIDTH - tick, left + TAPE_WIDTH)
+        } else {
+            (left, left + tick)
+        };
+        draw_line(x0, y, x1, y, 2.0, WHITE);
+        if major {
+            let label = format!("{:.0}", mark);
+            let dims = measure_text(&label, None, 20, 1.0);
+            let x = if ticks_on_right {
+                left + TAPE_WIDTH - 18.0 - dims.width
+            } else {
+                left + 18.0
+            };
+            draw_text(label, x, y + 6.0, 20.0, LIGHTGRAY);
+        }
+    }
+
+    draw_rectangle(left - 4.0, center_y - 14.0, TAPE_WIDTH + 8.0, 28.0, BLACK);
+    draw_rectangle_lines(
+        left - 4.0,
+        center_y - 14.0,
+        TAPE_WIDTH + 8.0,
+        28.0,
+        2.0,
+        YELLOW,
+    );
+    let readout = format!("{:.0}", value);
+    let dims = measure_text(&readout, None, 26, 1.0);
+    draw_text(
+        readout,
+        left + TAPE_WIDTH * 0.5 - dims.width * 0.5,
+        center_y + 8.0,
+        26.0,
+        YELLOW,
+    );
+    draw_rectangle_lines(left, top, TAPE_WIDTH, TAPE_HEIGHT, 2.0, LIGHTGRAY);
+}
+
+fn draw_energy_bar(screen: Vec2, left: f32, energy: f32, rate: f32) {
+    let top = screen.y * 0.5 - TAPE_HEIGHT * 0.5;
+    let fill = (energy / ENERGY_BAR_MAX).clamp(0.0, 1.0) * TAPE_HEIGHT;
+    draw_rectangle(
+        left,
+        top,
+        THROTTLE_BAR_WIDTH,
+        TAPE_HEIGHT,
+        Color::new(0.0, 0.0, 0.0, 0.45),
+    );
+    draw_rectangle(
+        left,
+        top + TAPE_HEIGHT - fill,
+        THROTTLE_BAR_WIDTH,
+        fill,
+        SKYBLUE,
+    );
+    draw_rectangle_lines(left, top, THROTTLE_BAR_WIDTH, TAPE_HEIGHT, 2.0, LIGHTGRAY);
+    let (label, color) = if rate > 0.5 {
+        ("+", GREEN)
+    } else if rate < -0.5 {
+        ("-", RED)
+    } else {
+        ("=", LIGHTGRAY)
+    };
+    let dims = measure_text(label, None, 28, 1.0);
+    draw_text(
+        label,
+        left + THROTTLE_BAR_WIDTH * 0.5 - dims.width * 0.5,
+        top - 8.0,
+        28.0,
+        color,
+    );
+}
+
+fn draw_throttle_bar(screen: Vec2, left: f32, throttle: f32) {
+    let top = screen.y * 0.5 - TAPE_HEIGHT * 0.5;
+    let fill = (throttle / 1.4).clamp(0.0, 1.0) * TAPE_HEIGHT;
+    draw_rectangle(
+        left,
+        top,
+        THROTTLE_BAR_WIDTH,
+        TAPE_HEIGHT,
+        Color::new(0.0, 0.0, 0.0, 0.45),
+    );
+    let color = if throttle > 1.0 { ORANGE } else { GREEN };
+    draw_rectangle(
+        left,
+        top + TAPE_HEIGHT - fill,
+        THROTTLE_BAR_WIDTH,
+        fill,
+        color,
+    );
+    let full = top + TAPE_HEIGHT - TAPE_HEIGHT / 1.4;
+    draw_line(
+        left - 3.0,
+        full,
+        left + THROTTLE_BAR_WIDTH + 3.0,
+        full,
+        2.0,
+        WHITE,
+    );
+    draw_rectangle_lines(left, top, THROTTLE_BAR_WIDTH, TAPE_HEIGHT, 2.0, LIGHTGRAY);
+}
+
+fn draw_vsi(center: Vec2, vertical_speed: f32, units: UnitSystem) {
+    let color = if vertical_speed < -VSI_SINK_WARNING {
+        RED
+    } else if vertical_speed > 0.5 {
+        GREEN
+    } else {
+        WHITE
+    };
+    let direction = |value: f32| {
+        let angle = (value / VSI_RANGE).clamp(-1.0, 1.0) * 150f32.to_radians();
+        Vec2::new(-angle.cos(), -angle.sin())
+    };
+
+    draw_circle(
+        center.x,
+        center.y,
+        VSI_RADIUS,
+        Color::new(0.0, 0.0, 0.0, 0.55),
+    );
+    for step in -4..=4 {
+        let dir = direction(step as f32 * 5.0);
+        let outer = center + dir * VSI_RADIUS;
+        let inner = center + dir * (VSI_RADIUS - if step % 2 == 0 { 10.0 } else { 5.0 });
+        draw_line(inner.x, inner.y, outer.x, outer.y, 2.0, LIGHTGRAY);
+    }
+    let tip = center + direction(vertical_speed) * (VSI_RADIUS - 6.0);
+    draw_line(center.x, center.y, tip.x, tip.y, 3.0, color);
+    draw_circle(center.x, center.y, 3.0, color);
+    draw_circle_lines(center.x, center.y, VSI_RADIUS, 2.0, LIGHTGRAY);
+
+    let decimals = if units == UnitSystem::Imperial { 0 } else { 1 };
+    let readout = format!(
+        "{:+.*} {}",
+        decimals,
+        units.vertical_speed(vertical_speed),
+        units.vertical_speed_unit()
+    );
+    let dims = measure_text(&readout, None, 20, 1.0);
+    draw_text(
+        readout,
+        center.x - dims.width * 0.5,
+        center.y + VSI_RADIUS + 20.0,
+        20.0,
+        color,
+    );
+}
+
+fn draw_slip_ball(center: Vec2, lateral_g: f32) {
+    let half = SLIP_BALL_WIDTH * 0.5;
+    let tube = |x: f32| center + Vec2::new(x, -SLIP_BALL_SAG * (x / half).powi(2));
+    let steps = 16;
+    let points: Vec<Vec2> = (0..=steps)
+        .map(|i| tube(-half + SLIP_BALL_WIDTH * i as f32 / steps as f32))
+        .collect();
+    for pair in points.windows(2) {
+        draw_line(
+            pair[0].x,
+            pair[0].y,
+            pair[1].x,
+            pair[1].y,
+            SLIP_BALL_RADIUS * 2.0 + 4.0,
+            Color::new(0.0, 0.0, 0.0, 0.55),
+        );
+    }
+    for side in [-1.0, 1.0] {
+        let mark = tube(side * (SLIP_BALL_RADIUS + 2.0));
+        draw_line(
+            mark.x,
+            mark.y - SLIP_BALL_RADIUS - 2.0,
+            mark.x,
+            mark.y + SLIP_BALL_RADIUS + 2.0,
+            2.0,
+            LIGHTGRAY,
+        );
+    }
+    let offset = (-lateral_g / SLIP_BALL_RANGE).clamp(-1.0, 1.0) * (half - SLIP_BALL_RADIUS);
+    let ball = tube(offset);
+    draw_circle(ball.x, ball.y, SLIP_BALL_RADIUS, WHITE);
+}
+
+fn draw_ils(center: Vec2, deviation: Vec2) {
+    let half = ILS_SIZE * 0.5;
+    draw_rectangle(
+        center.x - half,
+        center.y - half,
+        ILS_SIZE,
+        ILS_SIZE,
+        Color::new(0.0, 0.0, 0.0, 0.55),
+    );
+    draw_rectangle_lines(
+        center.x - half,
+        center.y - half,
+        ILS_SIZE,
+        ILS_SIZE,
+        2.0,
+        LIGHTGRAY,
+    );
+    for dot in [-1.0, -0.5, 0.5, 1.0] {
+        let offset = dot * (half - 10.0);
+        draw_circle_lines(center.x + offset, center.y, 2.5, 1.0, LIGHTGRAY);
+        draw_circle_lines(center.x, center.y + offset, 2.5, 1.0, LIGHTGRAY);
+    }
+    draw_line(
+        center.x - 8.0,
+        center.y,
+        center.x + 8.0,
+        center.y,
+        2.0,
+        YELLOW,
+    );
+    let needle = Color::new(1.0, 0.3, 1.0, 1.0);
+    let x = center.x + deviation.x * (half - 10.0);
+    let y = center.y - deviation.y * (half - 10.0);
+    draw_line(
+        x,
+        center.y - half + 6.0,
+        x,
+        center.y + half - 6.0,
+        3.0,
+        needle,
+    );
+    draw_line(
+        center.x - half + 6.0,
+        y,
+        center.x + half - 6.0,
+        y,
+        3.0,
+        needle,
+    );
+    draw_text(
+        "ILS",
+        center.x - half,
+        center.y - half - 6.0,
+        22.0,
+        LIGHTGRAY,
+    );
+}
+
+fn draw_mouse_stick(screen: Vec2, stick: Vec2) {
+    let center = screen * 0.5;
+    let range = center.min_element() * MOUSE_FLIGHT_RANGE;
+    let color = Color::new(1.0, 1.0, 1.0, 0.6);
+    draw_circle_lines(center.x, center.y, range * STICK_DEADZONE, 1.0, color);
+    draw_rectangle_lines(
+        center.x - range,
+        center.y - range,
+        range * 2.0,
+        range * 2.0,
+        1.0,
+        Color::new(1.0, 1.0, 1.0, 0.2),
+    );
+    let cursor = center + stick * range;
+    draw_line(center.x, center.y, cursor.x, cursor.y, 1.0, color);
+    draw_circle_lines(cursor.x, cursor.y, 6.0, 2.0, WHITE);
+}
+
+fn draw_pitch_ladder(center: Vec2, pitch: f32, roll: f32) {
+    let rotation = Mat2::from_angle(roll.to_radians());
+    let to_screen = |local: Vec2| center + rotation * local;
+    let color = Color::new(0.2, 1.0, 0.4, 0.8);
+    for degrees in (-90i32..=90).step_by(LADDER_STEP) {
+        let offset = pitch - degrees as f32;
+        if offset.abs() > LADDER_VISIBLE {
+            continue;
+        }
+        let y = offset * LADDER_PX_PER_DEG;
+        if degrees == 0 {
+            let left = to_screen(Vec2::new(-LADDER_HALF_WIDTH * 1.6, y));
+            let right = to_screen(Vec2::new(LADDER_HALF_WIDTH * 1.6, y));
+            draw_line(left.x, left.y, right.x, right.y, 2.0, color);
+            continue;
+        }
+        let tick = if degrees > 0 {
+            LADDER_TICK
+        } else {
+            -LADDER_TICK
+        };
+        for side in [-1.0, 1.0] {
+            let inner = side * LADDER_GAP;
+            let outer = side * LADDER_HALF_WIDTH;
+            if degrees > 0 {
+                let start = to_screen(Vec2::new(inner, y));
+                let end = to_screen(Vec2::new(outer, y));
+                draw_line(start.x, start.y, end.x, end.y, 2.0, color);
+            } else {
+                let dash = (outer - inner) / (LADDER_DASHES * 2 - 1) as f32;
+                for index in 0..LADDER_DASHES {
+                    let from = inner + dash * (index * 2) as f32;
+                    let start = to_screen(Vec2::new(from, y));
+                    let end = to_screen(Vec2::new(from + dash, y));
+                    draw_line(start.x, start.y, end.x, end.y, 2.0, color);
+                }
+            }
+            let corner = to_screen(Vec2::new(outer, y));
+            let tip = to_screen(Vec2::new(outer, y + tick));
+            draw_line(corner.x, corner.y, tip.x, tip.y, 2.0, color);
+            let label = format!("{}", degrees.abs());
+            let dims = measure_text(&label, None, 18, 1.0);
+            let anchor = to_screen(Vec2::new(outer + side * (dims.width * 0.5 + 8.0), y));
+            draw_text(
+                &label,
+                anchor.x - dims.width * 0.5,
+                anchor.y + dims.offset_y * 0.5,
+                18.0,
+                color,
+            );
+        }
+    }
+}
+
+fn draw_flight_path_marker(screen: Vec2, camera: &Camera3D, velocity: Vec3) {
+    let Some(direction) = velocity.try_normalize() else {
+        return;
+    };
+    let clip = camera.matrix() * (camera.position + direction * 1000.0).extend(1.0);
+    let ndc = clip.xy() / clip.w.abs().max(0.001);
+    let half = screen * 0.5;
+    let mut offset = Vec2::new(ndc.x, -ndc.y) * half;
+    let limit = half - Vec2::splat(FPM_EDGE_MARGIN);
+    let clamped = clip.w <= 0.0 || offset.x.abs() > limit.x || offset.y.abs() > limit.y;
+    if clamped {
+        let scale = (offset.x.abs() / limit.x).max(offset.y.abs() / limit.y);
+        offset = if clip.w <= 0.0 {
+            -offset.normalize_or(Vec2::Y) * limit.length()
+        } else {
+            offset / scale
+        };
+        offset = offset.clamp(-limit, limit);
+    }
+    let center = half + offset;
+    let color = if clamped {
+        Color::new(0.2, 1.0, 0.4, 0.45)
+    } else {
+        Color::new(0.2, 1.0, 0.4, 0.9)
+    };
+    draw_circle_lines(center.x, center.y, FPM_RADIUS, 2.0, color);
+    draw_line(
+        center.x - FPM_RADIUS * 2.4,
+        center.y,
+        center.x - FPM_RADIUS,
+        center.y,
+        2.0,
+        color,
+    );
+    draw_line(
+        center.x + FPM_RADIUS,
+        center.y,
+        center.x + FPM_RADIUS * 2.4,
+        center.y,
+        2.0,
+        color,
+    );
+    draw_line(
+        center.x,
+        center.y - FPM_RADIUS,
+        center.x,
+        center.y - FPM_RADIUS * 1.8,
+        2.0,
+        color,
+    );
+}
+
+fn dial_direction(fraction: f32) -> Vec2 {
+    let angle = (fraction.clamp(0.0, 1.0) * 300.0 - 150.0).to_radians();
+    Vec2::new(angle.sin(), -angle.cos())
+}
+
+fn draw_needle(center: Vec2, direction: Vec2, length: f32, thickness: f32, color: Color) {
+    let tip = center + direction * length;
+    draw_line(center.x, center.y, tip.x, tip.y, thickness, color);
+}
+
+fn draw_gauge_face(center: Vec2, label: &str) {
+    draw_circle(
+        center.x,
+        center.y,
+        COCKPIT_GAUGE_RADIUS,
+        Color::new(0.04, 0.04, 0.05, 1.0),
+    );
+    draw_circle_lines(center.x, center.y, COCKPIT_GAUGE_RADIUS, 4.0, GRAY);
+    let dims = measure_text(label, None, 18, 1.0);
+    draw_text(
+        label,
+        center.x - dims.width * 0.5,
+        center.y + COCKPIT_GAUGE_RADIUS * 0.5,
+        18.0,
+        LIGHTGRAY,
+    );
+}
+
+fn draw_dial_labels(center: Vec2, labels: &[(Vec2, String)]) {
+    for (direction, label) in labels {
+        let dims = measure_text(label, None, 18, 1.0);
+        let anchor = center + *direction * (COCKPIT_GAUGE_RADIUS - 26.0);
+        draw_text(
+            label,
+            anchor.x - dims.width * 0.5,
+            anchor.y + 6.0,
+            18.0,
+            WHITE,
+        );
+    }
+}
+
+fn draw_dial_ticks(center: Vec2, directions: impl Iterator<Item = (Vec2, bool)>) {
+    for (direction, major) in directions {
+        let outer = center + direction * (COCKPIT_GAUGE_RADIUS - 4.0);
+        let inner = center + direction * (COCKPIT_GAUGE_RADIUS - if major { 16.0 } else { 10.0 });
+        draw_line(inner.x, inner.y, outer.x, outer.y, 2.0, WHITE);
+    }
+}
+
+fn draw_airspeed_dial(center: Vec2, speed: f32, full_scale: f32, unit: &str) {
+    draw_gauge_face(center, unit);
+    let steps = 20;
+    draw_dial_ticks(
+        center,
+        (0..=steps).map(|step| (dial_direction(step as f32 / steps as f32), step % 4 == 0)),
+    );
+    let labels: Vec<(Vec2, String)> = (0..=steps)
+        .step_by(4)
+        .map(|step| {
+            let fraction = step as f32 / steps as f32;
+            (
+                dial_direction(fraction),
+                format!("{:.0}", full_scale * fraction),
+            )
+        })
+        .collect();
+    draw_dial_labels(center, &labels);
+    draw_needle(
+        center,
+        dial_direction(speed / full_scale),
+        COCKPIT_GAUGE_RADIUS - 12.0,
+        4.0,
+        WHITE,
+    );
+    draw_circle(center.x, center.y, 5.0, GRAY);
+}
+
+fn draw_altimeter(center: Vec2, altitude: f32, unit: &str) {
+    draw_gauge_face(center, &format!("x100 {}", unit));
+    let around = |fraction: f32| {
+        let angle = fraction * std::f32::consts::TAU;
+        Vec2::new(angle.sin(), -angle.cos())
+    };
+    draw_dial_ticks(
+        center,
+        (0..50).map(|step| (around(step as f32 / 50.0), step % 5 == 0)),
+    );
+    let labels: Vec<(Vec2, String)> = (0..10)
+        .map(|digit| (around(digit as f32 / 10.0), digit.to_string()))
+        .collect();
+    draw_dial_labels(center, &labels);
+    let revolutions = altitude.max(0.0) / ALTIMETER_REVOLUTION;
+    draw_needle(
+        center,
+        around(revolutions / 10.0),
+        COCKPIT_GAUGE_RADIUS * 0.45,
+        6.0,
+        LIGHTGRAY,
+    );
+    draw_needle(
+        center,
+        around(revolutions.fract()),
+        COCKPIT_GAUGE_RADIUS - 12.0,
+        3.0,
+        WHITE,
+    );
+    draw_circle(center.x, center.y, 5.0, GRAY);
+}
+
+fn draw_heading_dial(center: Vec2, heading: f32, bearing: f32) {
+    draw_gauge_face(center, "");
+    let card = |degrees: f32| {
+        let angle = (degrees - heading).to_radians();
+        Vec2::new(angle.sin(), -angle.cos())
+    };
+    draw_dial_ticks(
+        center,
+        (0..36).map(|step| (card(step as f32 * 10.0), step % 3 == 0)),
+    );
+    let labels: Vec<(Vec2, String)> = (0..12)
+        .map(|step| {
+            let label = match step {
+                0 => "N".to_string(),
+                3 => "E".to_string(),
+                6 => "S".to_string(),
+                9 => "W".to_string(),
+                step => format!("{}", step * 3),
+            };
+            (card(step as f32 * 30.0), label)
+        })
+        .collect();
+    draw_dial_labels(center, &labels);
+    let bug = center + card(bearing) * (COCKPIT_GAUGE_RADIUS - 4.0);
+    draw_circle(bug.x, bug.y, 5.0, ORANGE);
+    draw_triangle(
+        center + Vec2::new(0.0, -18.0),
+        center + Vec2::new(-12.0, 14.0),
+        center + Vec2::new(12.0, 14.0),
+        YELLOW,
+    );
+    draw_line(
+        center.x,
+        center.y - COCKPIT_GAUGE_RADIUS + 2.0,
+        center.x,
+        center.y - COCKPIT_GAUGE_RADIUS + 18.0,
+        3.0,
+        YELLOW,
+    );
+}
+
+fn draw_cockpit_panel(
+    screen: Vec2,
+    plane: &Plane,
+    units: UnitSystem,
+    language: Language,
+    bearing: f32,
+) {
+    let top = screen.y - COCKPIT_PANEL_HEIGHT;
+    draw_rectangle(
+        0.0,
+        top,
+        screen.x,
+        COCKPIT_PANEL_HEIGHT,
+        Color::new(0.16, 0.17, 0.19, 1.0),
+    );
+    draw_rectangle(0.0, top, screen.x, 8.0, Color::new(0.08, 0.08, 0.09, 1.0));
+    let center_y = top + COCKPIT_PANEL_HEIGHT * 0.5 + 4.0;
+    let spacing = COCKPIT_GAUGE_SPACING.min(screen.x / 5.0);
+    let slot = |index: f32| Vec2::new(screen.x * 0.5 + index * spacing, center_y);
+    draw_heading_dial(slot(-2.0), plane.heading(), bearing);
+    draw_airspeed_dial(
+        slot(-1.0),
+        units.speed(plane.indicated_airspeed()),
+        units.speed(plane.config.max_speed * 1.2),
+        units.speed_unit(),
+    );
+    draw_attitude_indicator(slot(0.0), plane.pitch(), plane.roll());
+    draw_altimeter(
+        slot(1.0),
+        units.altitude(plane.position.y),
+        units.altitude_unit(),
+    );
+    draw_vsi(slot(2.0), plane.vertical_speed, units);
+    let status = format!(
+        "{} {:.0}% | {} {:.0}% | Flap {:.0}% | {} {}",
+        language.text("Gaz", "Throttle"),
+        plane.throttle * 100.0,
+        language.text("Yakıt", "Fuel"),
+        plane.fuel_percent(),
+        plane.flaps * 100.0,
+        language.text("Takım", "Gear"),
+        language.on_off(plane.gear_down)
+    );
+    draw_text(status, 24.0, top + 30.0, 22.0, LIGHTGRAY);
+}
+
+fn draw_compass(screen: Vec2, heading: f32, bearing: f32) {
+    let center_x = screen.x * 0.5;
+    let top = 8.0;
+    let width = COMPASS_WIDTH.min(screen.x * 0.3);
+    let half_span = width * 0.5 / COMPASS_PX_PER_DEG;
+    draw_rectangle(
+        center_x - width * 0.5,
+        top,
+        width,
+        COMPASS_HEIGHT,
+        Color::new(0.0, 0.0, 0.0, 0.45),
+    );
+
+    let first = ((heading - half_span) / 5.0).ceil() as i32;
+    let last = ((heading + half_span) / 5.0).floor() as i32;
+    for step in first..=last {
+        let mark = step * 5;
+        let x = center_x + wrap_degrees(mark as f32 - heading) * COMPASS_PX_PER_DEG;
+        let major = mark % 10 == 0;
+        let tick = if major { 12.0 } else { 6.0 };
+        draw_line(x, top, x, top + tick, 2.0, WHITE);
+        if mark % 30 != 0 {
+            continue;
+        }
+        let label = match mark.rem_euclid(360) {
+            0 => "N".to_string(),
+            90 => "E".to_string(),
+            180 => "S".to_string(),
+            270 => "W".to_string(),
+            degrees => format!("{}", degrees / 10),
+        };
+        let dims = measure_text(&label, None, 22, 1.0);
+        draw_text(
+            label,
+            x - dims.width * 0.5,
+            top + COMPASS_HEIGHT - 6.0,
+            22.0,
+            WHITE,
+        );
+    }
+
+    let relative = wrap_degrees(bearing - heading);
+    let bug_x = center_x + relative.clamp(-half_span, half_span) * COMPASS_PX_PER_DEG;
+    draw_rectangle(bug_x - 5.0, top + COMPASS_HEIGHT - 8.0, 10.0, 8.0, ORANGE);
+
+    let cue_y = top + COMPASS_HEIGHT + 20.0;
+    if relative.abs() > 3.0 {
+        let side = relative.signum();
+        let tip = center_x + side * 70.0;
+        draw_triangle(
+            Vec2::new(tip, cue_y),
+            Vec2::new(tip - side * 18.0, cue_y - 10.0),
+            Vec2::new(tip - side * 18.0, cue_y + 10.0),
+            ORANGE,
+        );
+    }
+
+    draw_triangle(
+        Vec2::new(center_x, top + COMPASS_HEIGHT - 4.0),
+        Vec2::new(center_x - 8.0, top + COMPASS_HEIGHT + 8.0),
+        Vec2::new(center_x + 8.0, top + COMPASS_HEIGHT + 8.0),
+        YELLOW,
+    );
+    let readout = format!("{:03.0}", heading.rem_euclid(360.0).round() % 360.0);
+    let dims = measure_text(&readout, None, 24, 1.0);
+    draw_text(
+        readout,
+        center_x - dims.width * 0.5,
+        top + COMPASS_HEIGHT + 28.0,
+        24.0,
+        YELLOW,
+    );
+    draw_rectangle_lines(
+        center_x - width * 0.5,
+        top,
+        width,
+        COMPASS_HEIGHT,
+        2.0,
+        LIGHTGRAY,
+    );
+}
+
+fn draw_minimap(screen: Vec2, plane: &Plane, world: &World) {
+    let origin = Vec2::new(
+        screen.x - MINIMAP_SIZE - 20.0,
+        screen.y - MINIMAP_SIZE - 20.0,
+    );
+    let scale = MINIMAP_SIZE / (WORLD_HALF_EXTENT * 2.0);
+    let to_map = |x: f32, z: f32| origin + (Vec2::new(x, z) + WORLD_HALF_EXTENT) * scale;
+
+    draw_rectangle(
+        origin.x,
+        origin.y,
+        MINIMAP_SIZE,
+        MINIMAP_SIZE,
+        Color::new(0.1, 0.2, 0.1, 0.6),
+    );
+    draw_rectangle_lines(
+        origin.x,
+        origin.y,
+        MINIMAP_SIZE,
+        MINIMAP_SIZE,
+        2.0,
+        LIGHTGRAY,
+    );
+
+    let runway = to_map(
+        RUNWAY_CENTER.x - RUNWAY_WIDTH * 0.5,
+        RUNWAY_CENTER.y - RUNWAY_LENGTH * 0.5,
+    );
+    draw_rectangle(
+        runway.x,
+        runway.y,
+        (RUNWAY_WIDTH * scale).max(2.0),
+        RUNWAY_LENGTH * scale,
+        GRAY,
+    );
+
+    for cloud in world.clouds.iter().filter(|cloud| !cloud.popped) {
+        let marker = to_map(cloud.center.x, cloud.center.z);
+        let color = if world.solid_clouds { ORANGE } else { WHITE };
+        draw_rectangle(marker.x - 2.0, marker.y - 2.0, 4.0, 4.0, color);
+    }
+
+    let target = world.route.target();
+    let marker = to_map(target.x, target.z);
+    draw_circle_lines(marker.x, marker.y, 4.0, 2.0, ORANGE);
+    if world.scenario == Scenario::RingRace {
+        let gate = world.rings.target().center;
+        let marker = to_map(gate.x, gate.z);
+        draw_circle_lines(marker.x, marker.y, 5.0, 2.0, SKYBLUE);
+    }
+
+    for traffic in &world.traffic {
+        let marker = to_map(traffic.plane.position.x, traffic.plane.position.z)
+            .clamp(origin, origin + Vec2::splat(MINIMAP_SIZE));
+        draw_circle(marker.x, marker.y, 3.0, RED);
+    }
+
+    let position = to_map(plane.position.x, plane.position.z)
+        .clamp(origin, origin + Vec2::splat(MINIMAP_SIZE));
+    let forward = plane.forward();
+    let heading = Vec2::new(forward.x, forward.z).normalize_or(Vec2::NEG_Y);
+    let side = heading.perp();
+    draw_triangle(
+        position + heading * 9.0,
+        position - heading * 5.0 + side * 5.0,
+        position - heading * 5.0 - side * 5.0,
+        YELLOW,
+    );
+}
+
+fn draw_vision_effects(screen: Vec2, plane: &Plane) {
+    let (width, height) = (screen.x, screen.y);
+    if plane.blackout > 0.0 {
+        draw_rectangle(
+            0.0,
+            0.0,
+            width,
+            height,
+            Color::new(0.35, 0.35, 0.35, plane.blackout * 0.35),
+        );
+        let reach = width.min(height) * 0.5 * plane.blackout;
+        let band = reach / VIGNETTE_BANDS as f32;
+        for i in 0..VIGNETTE_BANDS {
+            let inset = i as f32 * band;
+            let alpha = (1.0 - i as f32 / VIGNETTE_BANDS as f32) * 0.35;
+            let color = Color::new(0.0, 0.0, 0.0, alpha);
+            draw_rectangle(inset, inset, width - inset * 2.0, band, color);
+            draw_rectangle(
+                inset,
+                height - inset - band,
+                width - inset * 2.0,
+                band,
+                color,
+            );
+            draw_rectangle(
+                inset,
+                inset + band,
+                band,
+                height - (inset + band) * 2.0,
+                color,
+            );
+            draw_rectangle(
+                width - inset - band,
+                inset + band,
+                band,
+                height - (inset + band) * 2.0,
+                color,
+            );
+        }
+        let blackout = ((plane.blackout - 0.7) / 0.3).clamp(0.0, 1.0);
+        draw_rectangle(0.0, 0.0, width, height, Color::new(0.0, 0.0, 0.0, blackout));
+    }
+    if plane.redout > 0.0 {
+        draw_rectangle(
+            0.0,
+            0.0,
+            width,
+            height,
+            Color::new(0.7, 0.0, 0.0, plane.redout * 0.6),
+        );
+    }
+}
+
+fn draw_hud(
+    plane: &Plane,
+    camera: &Camera3D,
+    input: &InputState,
+    world: &World,
+    replay: &Replay,
+    settings: &HudSettings,
+    clock: &SimClock,
+) {
+    let weather = &world.weather;
+    let screen = Vec2::new(screen_width(), screen_height()) / settings.scale();
+    set_camera(&Camera2D {
+        target: screen * 0.5,
+        zoom: Vec2::new(2.0 / screen.x, 2.0 / screen.y),
+        ..Default::default()
+    });
+    let units = settings.units;
+    let language = settings.language;
+    if !input.cockpit {
+        draw_altitude_labels(screen, camera, units);
+        draw_flight_path_marker(screen, camera, plane.velocity);
+    }
+    if let Some(stick) = input.mouse_stick {
+        draw_mouse_stick(screen, stick);
+    }
+    let readouts = &settings.readouts;
+    let speed = readouts.speed;
+    let altitude = readouts.altitude;
+    let throttle = (plane.throttle * 100.0).clamp(0.0, 140.0);
+    let pitch = plane.pitch();
+    let yaw = plane.heading();
+    let roll = plane.roll();
+    if !input.cockpit {
+        draw_pitch_ladder(screen * 0.5, pitch, roll);
+    }
+    let bearing = world.route.bearing_from(plane.position);
+    let gear = match (plane.gear_down, plane.gear_extension) {
+        (true, extension) if extension >= 1.0 => language.on_off(true).to_string(),
+        (false, extension) if extension <= 0.0 => language.on_off(false).to_string(),
+        (_, extension) => format!(
+            "{} {:.0}%",
+            language.text("hareket", "moving"),
+            extension * 100.0
+        ),
+    };
+    let fuel = if plane.unlimited_fuel {
+        language.text("sınırsız", "unlimited").to_string()
+    } else {
+        format!("{:>5.1}%", plane.fuel_percent())
+    };
+    let wind = format!(
+        "{:.1} m/s{}",
+        weather.wind.length(),
+        if weather.turbulence {
+            language.text(" + türbülans", " + turbulence")
+        } else {
+            ""
+        }
+    );
+    let mut lines = vec![
+        format!(
+            "{}: {}",
+            language.text("Senaryo", "Scenario"),
+            world.scenario.label(language)
+        ),
+        format!(
+            "{}: {}",
+            language.text("Uçak", "Aircraft"),
+            plane.config.name
+        ),
+    ];
+    lines.push(format!(
+        "{} (TAS): {:>6.1} {}",
+        language.text("Hız", "Speed"),
+        units.speed(speed),
+        units.speed_unit()
+    ));
+    if !settings.tapes {
+        lines.push(format!(
+            "{} (IAS): {:>6.1} {}",
+            language.text("Hız", "Speed"),
+            units.speed(readouts.indicated),
+            units.speed_unit()
+        ));
+        lines.push(format!(
+            "{}: {:>6.1} {}",
+            language.text("İrtifa", "Altitude"),
+            units.altitude(altitude),
+            units.altitude_unit()
+        ));
+        lines.push(format!(
+            "{}: {:>5.1}%",
+            language.text("Gaz", "Throttle"),
+            throttle
+        ));
+    }
+    lines.push(format!(
+        "{}: {:.0} rpm",
+        language.text("Devir", "Engine"),
+        plane.engine_rpm * ENGINE_RATED_RPM
+    ));
+    lines.push(format!("{}: {}", language.text("Yakıt", "Fuel"), fuel));
+    lines.push(format!(
+        "{}: {:.0} kg",
+        language.text("Kütle", "Mass"),
+        plane.mass()
+    ));
+    lines.push(format!("Pitch: {:>5.1}°", pitch));
+    lines.push(format!("Roll: {:>5.1}°", roll));
+    lines.push(format!("Yaw: {:>5.1}°", yaw));
+    lines.push(format!(
+        "{}: {:>5.1}°",
+        language.text("Yan kayma", "Sideslip"),
+        plane.sideslip()
+    ));
+    lines.push(format!("G: {:>5.1}", readouts.g_force));
+    let energy = plane.energy_height(world.gravity.vector());
+    if let Some(energy) = energy {
+        lines.push(format!(
+            "{}: {:.0} {} ({:+.1} {}/s)",
+            language.text("Enerji", "Energy"),
+            units.altitude(energy),
+            units.altitude_unit(),
+            units.altitude(plane.energy_rate),
+            units.altitude_unit()
+        ));
+    }
+    let trims: Vec<String> = [
+        ("P", plane.pitch_trim / MAX_PITCH_TRIM),
+        ("R", plane.roll_trim / MAX_ROLL_TRIM),
+        ("Y", plane.yaw_trim / MAX_YAW_TRIM),
+    ]
+    .iter()
+    .filter(|(_, trim)| trim.abs() >= 0.005)
+    .map(|(axis, trim)| format!("{} {:+.0}%", axis, trim * 100.0))
+    .collect();
+    lines.push(if trims.is_empty() {
+        format!("Trim: {}", language.text("nötr", "neutral"))
+    } else {
+        format!("Trim: {}", trims.join(" | "))
+    });
+    lines.push(format!(
+        "{}: {}",
+        language.text("Fizik", "Physics"),
+        plane.physics.label(language)
+    ));
+    lines.push(format!(
+        "{}: {}",
+        language.text("İniş takımı", "Landing gear"),
+        gear
+    ));
+    lines.push(format!("Flap: {:.0}%", plane.flaps * 100.0));
+    lines.push(format!(
+        "{}: {:.0}%",
+        language.text("Art yakıcı ısısı", "Afterburner heat"),
+        plane.afterburner_heat * 100.0
+    ));
+    lines.push(format!("{}: {}", language.text("Rüzgar", "Wind"), wind));
+    let approach = ils_deviation(plane.position, plane.heading());
+    if world.scenario.is_landing() || approach.is_some() {
+        let runway = runway_wind(plane.position, weather.wind);
+        lines.push(format!(
+            "{}: {} {:+.1} m/s | {} {:.1} m/s {}",
+            language.text("Pist rüzgarı", "Runway wind"),
+            language.text("karşı", "head"),
+            runway.x,
+            language.text("yan", "cross"),
+            runway.y.abs(),
+            if runway.y >= 0.0 {
+                language.text("soldan", "from left")
+            } else {
+                language.text("sağdan", "from right")
+            }
+        ));
+        lines.push(format!(
+            "{}: {:+.1}°",
+            language.text("Yengeç açısı", "Crab angle"),
+            plane.crab_angle()
+        ));
+    }
+    lines.push(format!(
+        "{}: {} ({:.2} m/s²)",
+        language.text("Yerçekimi", "Gravity"),
+        world.gravity.label(language),
+        -world.gravity.vector().y
+    ));
+    lines.push(format!(
+        "{}: {}",
+        language.text("Saat", "Clock"),
+        world.time_of_day.clock()
+    ));
+    lines.push(format!(
+        "{}: WP{}/{} {:.0} m {:03.0}°",
+        language.text("Hedef", "Target"),
+        world.route.active + 1,
+        world.route.waypoints.len(),
+        plane.position.distance(world.route.target()),
+        bearing.rem_euclid(360.0)
+    ));
+    if world.solid_clouds {
+        lines.push(format!(
+            "{}: {}",
+            language.text("Engeller", "Obstacles"),
+            language.on_off(true)
+        ));
+    }
+    if world.scenario == Scenario::RingRace {
+        let rings = &world.rings;
+        lines.push(format!(
+            "{}: {}/{} | {}: {:.1} s",
+            language.text("Halka", "Ring"),
+            rings.next + 1,
+            rings.gates.len(),
+            language.text("süre", "time"),
+            rings.elapsed(clock.elapsed).unwrap_or(0.0)
+        ));
+        if !rings.splits.is_empty() {
+            let shown = rings.splits.len().saturating_sub(RING_SPLITS_SHOWN);
+            let splits: Vec<String> = rings.splits[shown..]
+                .iter()
+                .enumerate()
+                .map(|(index, split)| format!("H{} {:.1}", shown + index + 1, split))
+                .collect();
+            lines.push(format!(
+                "{}: {}",
+                language.text("Ara süreler", "Splits"),
+                splits.join(" | ")
+            ));
+        }
+    }
+    let aerobatics = &world.aerobatics;
+    if aerobatics.points > 0 {
+        lines.push(format!(
+            "{}: {} {} | {}: {} | looping: {}",
+            language.text("Akrobasi", "Aerobatics"),
+            aerobatics.points,
+            language.text("puan", "points"),
+            language.text("tonoz", "rolls"),
+            aerobatics.rolls,
+            aerobatics.loops
+        ));
+    }
+    if world.scenario == Scenario::TargetPractice {
+        lines.push(format!(
+            "{}: {} | {}: {}",
+            language.text("Vuruş", "Hits"),
+            world.targets_hit,
+            language.text("kalan hedef", "targets left"),
+            world.targets_left()
+        ));
+    }
+    let best = |value: Option<f32>, unit: &str| {
+        value.map_or("-".to_string(), |value| format!("{:.0}{}", value, unit))
+    };
+    lines.push(format!(
+        "{}: {} {} | {} {} | {} {}",
+        language.text("Rekor", "Best"),
+        language.text("iniş", "landing"),
+        best(world.scores.landing, ""),
+        language.text("parkur", "course"),
+        best(world.scores.course, " s"),
+        language.text("halka", "rings"),
+        best(world.scores.rings, " s")
+    ));
+
+    if input.cockpit {
+        draw_cockpit_panel(screen, plane, units, language, bearing);
+    } else {
+        let mut info_x = 24.0;
+        if settings.tapes {
+            draw_tape(
+                screen,
+                24.0,
+                units.speed(readouts.indicated),
+                units.speed(40.0),
+                units.speed_step(),
+                true,
+            );
+            draw_throttle_bar(screen, 24.0 + TAPE_WIDTH + 10.0, plane.throttle);
+            draw_tape(
+                screen,
+                screen.x - 24.0 - TAPE_WIDTH,
+                units.altitude(altitude),
+                units.altitude(100.0),
+                units.altitude_step(),
+                false,
+            );
+            if let Some(energy) = energy {
+                draw_energy_bar(
+                    screen,
+                    screen.x - 24.0 - TAPE_WIDTH - 10.0 - THROTTLE_BAR_WIDTH,
+                    energy,
+                    plane.energy_rate,
+                );
+            }
+            info_x += TAPE_WIDTH + THROTTLE_BAR_WIDTH + 30.0;
+        }
+        draw_multiline_text(lines.join("\n"), info_x, 32.0, 28.0, None, WHITE);
+
+        draw_compass(screen, yaw, bearing);
+        draw_minimap(screen, plane, world);
+
+        draw_attitude_indicator(
+            Vec2::new(screen.x * 0.5, screen.y - 64.0 - ATTITUDE_RADIUS),
+            pitch,
+            roll,
+        );
+        draw_vsi(
+            Vec2::new(
+                screen.x * 0.5 + ATTITUDE_RADIUS + 20.0 + VSI_RADIUS,
+                screen.y - 64.0 - ATTITUDE_RADIUS,
+            ),
+            plane.vertical_speed,
+            units,
+        );
+        draw_slip_ball(
+            Vec2::new(
+                screen.x * 0.5 - ATTITUDE_RADIUS - 20.0 - SLIP_BALL_WIDTH * 0.5,
+                screen.y - 64.0 - ATTITUDE_RADIUS * 0.5,
+            ),
+            plane.lateral_g,
+        );
+        if let Some(deviation) = approach {
+            draw_ils(
+                Vec2::new(
+                    screen.x * 0.5 + ATTITUDE_RADIUS + 40.0 + VSI_RADIUS * 2.0 + ILS_SIZE * 0.5,
+                    screen.y - 64.0 - ATTITUDE_RADIUS,
+                ),
+                deviation,
+            );
+        }
+
+        let controls = language.text(CONTROLS_SHORT, CONTROLS_SHORT_EN);
+        let dims = measure_text(controls, None, 22, 1.0);
+        draw_text(
+            controls,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y - 24.0,
+            22.0,
+            LIGHTGRAY,
+        );
+    }
+
+    if input.brake {
+        let (label, color) = if plane.on_ground {
+            (
+                language.text("TEKERLEK FRENİ", "WHEEL BRAKE"),
+                Color::new(0.9, 0.15, 0.15, 0.8),
+            )
+        } else {
+            (
+                language.text("HAVA FRENİ", "AIR BRAKE"),
+                Color::new(0.9, 0.55, 0.1, 0.8),
+            )
+        };
+        let dims = measure_text(label, None, 34, 1.0);
+        draw_rectangle(
+            screen.x * 0.5 - dims.width * 0.5 - 28.0,
+            screen.y * 0.5 - 28.0,
+            dims.width + 56.0,
+            56.0,
+            color,
+        );
+        draw_text(
+            label,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 10.0,
+            34.0,
+            WHITE,
+        );
+    }
+
+    if plane.over_g_time > 0.0 && (get_time() * 4.0) as i64 % 2 == 0 {
+        let dims = measure_text("OVER-G", None, 48, 1.0);
+        draw_text(
+            "OVER-G",
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 - 60.0,
+            48.0,
+            RED,
+        );
+    }
+
+    if plane.is_spinning() && !plane.crashed {
+        let dims = measure_text("SPIN", None, 56, 1.0);
+        draw_text(
+            "SPIN",
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 100.0,
+            56.0,
+            RED,
+        );
+        let hint = format!(
+            "{} ({}), {}",
+            language.text("Karşı pedal", "Opposite rudder"),
+            if plane.spin_direction > 0.0 { "E" } else { "Q" },
+            language.text("lövye ortada", "stick centred")
+        );
+        let dims = measure_text(&hint, None, 26, 1.0);
+        draw_text(
+            &hint,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 128.0,
+            26.0,
+            ORANGE,
+        );
+    } else if plane.stall_warning() && (plane.stalled || (get_time() * 6.0) as i64 % 2 == 0) {
+        let dims = measure_text("STALL", None, 48, 1.0);
+        draw_text(
+            "STALL",
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 100.0,
+            48.0,
+            RED,
+        );
+    }
+
+    let nearest_traffic = world
+        .traffic
+        .iter()
+        .map(|traffic| traffic.plane.position.distance(plane.position))
+        .fold(f32::INFINITY, f32::min);
+    if nearest_traffic < TRAFFIC_WARNING_DISTANCE {
+        let message = format!(
+            "TRAFFIC {:.0} {}",
+            units.altitude(nearest_traffic),
+            units.altitude_unit()
+        );
+        let dims = measure_text(&message, None, 32, 1.0);
+        draw_text(
+            &message,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 150.0,
+            32.0,
+            ORANGE,
+        );
+    }
+
+    if plane.boundary_excess() > 0.0 {
+        let message = language.text("SINIR: MERKEZE DÖNÜN", "BOUNDARY: TURN BACK TO CENTRE");
+        let dims = measure_text(message, None, 36, 1.0);
+        draw_text(
+            message,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 - 160.0,
+            36.0,
+            RED,
+        );
+    }
+
+    if plane.pull_up_warning() && (get_time() * 4.0) as i64 % 2 == 0 {
+        let dims = measure_text("PULL UP", None, 56, 1.0);
+        draw_text(
+            "PULL UP",
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 66.0,
+            56.0,
+            RED,
+        );
+    }
+
+    if plane.afterburner_overheated {
+        let dims = measure_text("AB OVERHEAT", None, 32, 1.0);
+        draw_text(
+            "AB OVERHEAT",
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 - 110.0,
+            32.0,
+            ORANGE,
+        );
+    }
+
+    match replay.mode {
+        ReplayMode::Recording => {
+            draw_text(
+                language.text("KAYIT", "REC"),
+                screen.x * 0.5 - 40.0,
+                112.0,
+                32.0,
+                RED,
+            );
+        }
+        ReplayMode::Playing => {
+            draw_text(
+                language.text("TEKRAR", "REPLAY"),
+                screen.x * 0.5 - 48.0,
+                112.0,
+                32.0,
+                SKYBLUE,
+            );
+        }
+        ReplayMode::Idle => {}
+    }
+
+    if plane.return_to_base {
+        draw_text(
+            format!(
+                "AP: RTB {:.0} {}",
+                units.altitude(plane.base_offset().length()),
+                units.altitude_unit()
+            ),
+            screen.x - 290.0,
+            32.0,
+            28.0,
+            GREEN,
+        );
+    } else if plane.autopilot {
+        draw_text(
+            format!(
+                "AP: ALT HOLD {:.0} {}",
+                units.altitude(plane.autopilot_altitude),
+                units.altitude_unit()
+            ),
+            screen.x - 290.0,
+            32.0,
+            28.0,
+            GREEN,
+        );
+    }
+
+    if plane.auto_throttle {
+        draw_text(
+            format!(
+                "A/T {:.0} {}",
+                units.speed(plane.auto_throttle_speed),
+                units.speed_unit()
+            ),
+            screen.x - 290.0,
+            128.0,
+            28.0,
+            GREEN,
+        );
+    }
+
+    if settings.force_vectors {
+        draw_force_legend(screen, plane, language);
+    }
+
+    if settings.debug_state {
+        draw_debug_state(screen, plane);
+    }
+
+    if plane.turn_assist {
+        draw_text("ASSIST", screen.x - 290.0, 64.0, 28.0, GREEN);
+    }
+
+    if input.recover && !plane.on_ground && !plane.crashed {
+        draw_text("RECOVER", screen.x - 290.0, 96.0, 28.0, ORANGE);
+    }
+
+    if plane.is_taxiing() && !plane.crashed {
+        draw_text("TAXI", screen.x - 290.0, 96.0, 28.0, SKYBLUE);
+    }
+
+    if let Some(prompt) = world
+        .coach
+        .as_ref()
+        .and_then(|coach| coach.prompt(plane, units, language))
+    {
+        let dims = measure_text(&prompt, None, 36, 1.0);
+        draw_rectangle(
+            screen.x * 0.5 - dims.width * 0.5 - 24.0,
+            screen.y * 0.18 - 40.0,
+            dims.width + 48.0,
+            60.0,
+            Color::new(0.05, 0.2, 0.35, 0.8),
+        );
+        draw_text(
+            &prompt,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.18,
+            36.0,
+            WHITE,
+        );
+    }
+
+    if settings.panel {
+        draw_settings_panel(screen, plane, settings);
+    }
+
+    if let Some(banner) = world.aerobatics.banner() {
+        let dims = measure_text(banner, None, 64, 1.0);
+        draw_text(
+            banner,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.4,
+            64.0,
+            GOLD,
+        );
+    }
+
+    if let Some(score) = plane.landing_score
+        && plane.on_ground
+    {
+        let message = format!(
+            "{}: {:.0}",
+            language.text("İNİŞ BAŞARILI - Puan", "LANDING SUCCESSFUL - Score"),
+            score
+        );
+        let dims = measure_text(&message, None, 40, 1.0);
+        draw_text(
+            &message,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.3,
+            40.0,
+            GREEN,
+        );
+    }
+
+    if clock.time_scale() != 1.0 {
+        let text = format!(
+            "{}: x{}",
+            language.text("Zaman", "Time"),
+            clock.time_scale()
+        );
+        let dims = measure_text(&text, None, 28, 1.0);
+        draw_text(
+            text,
+            screen.x * 0.5 - dims.width * 0.5,
+            148.0,
+            28.0,
+            SKYBLUE,
+        );
+    }
+
+    if settings.notice_timer > 0.0 {
+        let alpha = (settings.notice_timer / 0.5).min(1.0);
+        let dims = measure_text(&settings.notice, None, 32, 1.0);
+        draw_text(
+            &settings.notice,
+            screen.x * 0.5 - dims.width * 0.5,
+            184.0,
+            32.0,
+            Color::new(1.0, 1.0, 1.0, alpha),
+        );
+    }
+
+    if clock.paused {
+        let paused = language.text("DURAKLATILDI", "PAUSED");
+        let dims = measure_text(paused, None, 48, 1.0);
+        draw_text(
+            paused,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.4,
+            48.0,
+            WHITE,
+        );
+    }
+
+    draw_vision_effects(screen, plane);
+
+    if plane.crashed {
+        let message = language.text(
+            "KAZA - yeniden başlamak için R",
+            "CRASHED - press R to restart",
+        );
+        let dims = measure_text(message, None, 40, 1.0);
+        draw_rectangle(
+            0.0,
+            screen.y * 0.5 - 50.0,
+            screen.x,
+            100.0,
+            Color::new(0.1, 0.0, 0.0, 0.75),
+        );
+        draw_text(
+            message,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 - 2.0,
+            40.0,
+            RED,
+        );
+        let countdown = format!(
+            "{}: {:.0}",
+            language.text("Otomatik yeniden başlama", "Automatic restart"),
+            plane.respawn_timer.max(0.0).ceil()
+        );
+        let dims = measure_text(&countdown, None, 28, 1.0);
+        draw_text(
+            countdown,
+            screen.x * 0.5 - dims.width * 0.5,
+            screen.y * 0.5 + 34.0,
+            28.0,
+            WHITE,
+        );
+    }
+    set_default_camera();
+}
+
+struct ReplayFrame {
+    time: f32,
     position: Vec3,
-    velocity: Vec3,
     orientation: Quat,
     throttle: f32,
 }
 
-impl Plane {
+#[derive(Clone, Copy, PartialEq)]
+enum ReplayMode {
+    Idle,
+    Recording,
+    Playing,
+}
+
+struct Replay {
+    frames: Vec<ReplayFrame>,
+    mode: ReplayMode,
+    clock: f32,
+    cursor: usize,
+}
+
+impl Replay {
     fn new() -> Self {
         Self {
-            position: Vec3::new(0.0, 90.0, 0.0),
-            velocity: Vec3::new(0.0, 0.0, -50.0),
-            orientation: Quat::IDENTITY,
-            throttle: 0.7,
+            frames: Vec::new(),
+            mode: ReplayMode::Idle,
+            clock: 0.0,
+            cursor: 0,
         }
     }
 
-    fn forward(&self) -> Vec3 {
-        self.orientation * Vec3::new(0.0, 0.0, -1.0)
+    fn toggle_recording(&mut self) {
+        if self.mode == ReplayMode::Recording {
+            self.mode = ReplayMode::Idle;
+        } else {
+            self.frames.clear();
+            self.clock = 0.0;
+            self.mode = ReplayMode::Recording;
+        }
     }
 
-    fn right(&self) -> Vec3 {
-        self.orientation * Vec3::new(1.0, 0.0, 0.0)
+    fn toggle_playback(&mut self) {
+        if self.mode == ReplayMode::Playing {
+            self.mode = ReplayMode::Idle;
+        } else if !self.frames.is_empty() {
+            self.clock = 0.0;
+            self.cursor = 0;
+            self.mode = ReplayMode::Playing;
+        }
     }
 
-    fn up(&self) -> Vec3 {
-        self.orientation * Vec3::new(0.0, 1.0, 0.0)
+    fn record(&mut self, plane: &Plane, dt: f32) {
+        if self.mode != ReplayMode::Recording {
+            return;
+        }
+        self.clock += dt;
+        self.frames.push(ReplayFrame {
+            time: self.clock,
+            position: plane.position,
+            orientation: plane.orientation,
+            throttle: plane.throttle,
+        });
+        if self.frames.len() >= MAX_REPLAY_FRAMES {
+            self.mode = ReplayMode::Idle;
+        }
     }
 
-    fn update(&mut self, dt: f32, input: &InputState) {
-        let yaw_input = input.yaw_left as i8 as f32 - input.yaw_right as i8 as f32;
-        let pitch_input = input.pitch_up as i8 as f32 - input.pitch_down as i8 as f32;
-        let roll_input = input.roll_right as i8 as f32 - input.roll_left as i8 as f32;
+    fn play(&mut self, plane: &mut Plane, dt: f32) {
+        self.clock += dt;
+        while self.cursor + 1 < self.frames.len() && self.frames[self.cursor + 1].time <= self.clock
+        {
+            self.cursor += 1;
+        }
+        let frame = &self.frames[self.cursor];
+        if let Some(next) = self.frames.get(self.cursor + 1) {
+            plane.velocity = (next.position - frame.position) / (next.time - frame.time);
+        } else {
+            self.mode = ReplayMode::Idle;
+        }
+        plane.position = frame.position;
+        plane.orientation = frame.orientation;
+        plane.throttle = frame.throttle;
+        plane.engine_rpm = frame.throttle;
+    }
+}
 
-        self.throttle = (self.throttle + input.throttle_delta * THROTTLE_STEP * dt).clamp(0.1, 1.4);
+fn timestamp_millis() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default()
+}
 
-        let target_speed = MIN_SPEED + (MAX_SPEED - MIN_SPEED) * self.throttle;
-        let forward = self.forward();
-        let speed_along_forward = Vec3::dot(self.velocity, forward);
-        let thrust = forward * (target_speed - speed_along_forward) * 14.0;
+fn save_screenshot() {
+    let path = format!("screenshot-{}.png", timestamp_millis());
+    let screen = get_screen_data();
+    let row = screen.width as usize * 4;
+    let flipped: Vec<u8> = screen
+        .bytes
+        .chunks_exact(row)
+        .rev()
+        .flatten()
+        .copied()
+        .collect();
+    match image::save_buffer(
+        &path,
+        &flipped,
+        screen.width as u32,
+        screen.height as u32,
+        image::ColorType::Rgba8,
+    ) {
+        Ok(()) => println!("Saved screenshot {}", path),
+        Err(err) => warn!("Failed to save screenshot {}: {}", path, err),
+    }
+}
 
-        let speed = self.velocity.length().max(1.0);
-        let lift_dir = self.up();
-        let lift = lift_dir * speed * speed * LIFT_COEFF * forward.y.abs().max(0.08);
-        let drag = -self.velocity * speed * DRAG_COEFF;
-        let gravity = GRAVITY;
-        let net_force = thrust + lift + drag + gravity;
+struct LogRow {
+    time: f32,
+    position: Vec3,
+    velocity: Vec3,
+    euler: Vec3,
+    throttle: f32,
+}
 
-        self.velocity += net_force * dt;
-        self.position += self.velocity * dt;
+#[derive(Default)]
+struct BestScores {
+    landing: Option<f32>,
+    course: Option<f32>,
+    rings: Option<f32>,
+}
 
-        let rotation_delta = Quat::from_euler(
-            EulerRot::XYZ,
-            pitch_input * PITCH_RATE * dt,
-            yaw_input * YAW_RATE * dt,
-            roll_input * ROLL_RATE * dt,
-        );
-        self.orientation = (self.orientation * rotation_delta).normalize();
+impl BestScores {
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut scores = Self::default();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("malformed line '{}'", line))?;
+            let value: f32 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid value '{}'", value.trim()))?;
+            match key.trim() {
+                "landing" => scores.landing = Some(value),
+                "course" => scores.course = Some(value),
+                "rings" => scores.rings = Some(value),
+                other => return Err(format!("unknown key '{}'", other)),
+            }
+        }
+        Ok(scores)
+    }
+
+    fn load() -> Self {
+        let Ok(text) = std::fs::read_to_string(SCORES_PATH) else {
+            return Self::default();
+        };
+        Self::parse(&text).unwrap_or_else(|err| {
+            warn!("Ignoring corrupt scores file {}: {}", SCORES_PATH, err);
+            Self::default()
+        })
+    }
+
+    fn save(&self) {
+        let mut text = String::new();
+        if let Some(landing) = self.landing {
+            text.push_str(&format!("landing={}\n", landing));
+        }
+        if let Some(course) = self.course {
+            text.push_str(&format!("course={}\n", course));
+        }
+        if let Some(rings) = self.rings {
+            text.push_str(&format!("rings={}\n", rings));
+        }
+        if let Err(err) = std::fs::write(SCORES_PATH, text) {
+            warn!("Failed to save scores {}: {}", SCORES_PATH, err);
+        }
+    }
+
+    fn record_landing(&mut self, score: f32) {
+        if self.landing.is_none_or(|best| score > best) {
+            self.landing = Some(score);
+            self.save();
+        }
+    }
+
+    fn record_course(&mut self, time: f32) {
+        if self.course.is_none_or(|best| time < best) {
+            self.course = Some(time);
+            self.save();
+        }
+    }
 
-        if self.position.y < 2.5 {
-            self.position.y = 2.5;
-            self.velocity.y = self.velocity.y.max(0.0);
+    fn record_rings(&mut self, time: f32) {
+        if self.rings.is_none_or(|best| time < best) {
+            self.rings = Some(time);
+            self.save();
         }
     }
 }
 
-struct InputState {
-    roll_left: bool,
-    roll_right: bool,
-    pitch_up: bool,
-    pitch_down: bool,
-    yaw_left: bool,
-    yaw_right: bool,
-    throttle_delta: f32,
-    brake: bool,
-    cockpit: bool,
+struct FlightLog {
+    rows: VecDeque<LogRow>,
 }
 
-impl InputState {
-    fn gather() -> Self {
-        let throttle_raise = is_key_down(KeyCode::Equal) || is_key_down(KeyCode::KpAdd);
-        let throttle_lower = is_key_down(KeyCode::Minus) || is_key_down(KeyCode::KpSubtract);
+impl FlightLog {
+    fn new() -> Self {
         Self {
-            roll_left: is_key_down(KeyCode::A) || is_key_down(KeyCode::Left),
-            roll_right: is_key_down(KeyCode::D) || is_key_down(KeyCode::Right),
-            pitch_up: is_key_down(KeyCode::W) || is_key_down(KeyCode::Up),
-            pitch_down: is_key_down(KeyCode::S) || is_key_down(KeyCode::Down),
-            yaw_left: is_key_down(KeyCode::Q),
-            yaw_right: is_key_down(KeyCode::E),
-            throttle_delta: if throttle_raise { 1.0 } else { 0.0 }
-                - if throttle_lower { 1.0 } else { 0.0 },
-            brake: is_key_down(KeyCode::Space),
-            cockpit: is_key_down(KeyCode::C),
+            rows: VecDeque::new(),
+        }
+    }
+
+    fn record(&mut self, time: f32, plane: &Plane) {
+        if self.rows.len() >= MAX_LOG_ROWS {
+            self.rows.pop_front();
+        }
+        let (yaw, pitch, roll) = plane.orientation.to_euler(EulerRot::YXZ);
+        self.rows.push_back(LogRow {
+            time,
+            position: plane.position,
+            velocity: plane.velocity,
+            euler: Vec3::new(yaw, pitch, roll) * (180.0 / std::f32::consts::PI),
+            throttle: plane.throttle,
+        });
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("time,x,y,z,vx,vy,vz,yaw,pitch,roll,throttle\n");
+        for row in &self.rows {
+            csv.push_str(&format!(
+                "{:.3},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.3}\n",
+                row.time,
+                row.position.x,
+                row.position.y,
+                row.position.z,
+                row.velocity.x,
+                row.velocity.y,
+                row.velocity.z,
+                row.euler.x,
+                row.euler.y,
+                row.euler.z,
+                row.throttle,
+            ));
+        }
+        csv
+    }
+
+    fn export(&self) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let path = format!("flightlog-{}.csv", timestamp_millis());
+        match std::fs::write(&path, self.to_csv()) {
+            Ok(()) => println!("Saved flight log {} ({} rows)", path, self.rows.len()),
+            Err(err) => warn!("Failed to save flight log {}: {}", path, err),
         }
     }
 }
 
-fn update_camera(camera: &mut Camera3D, plane: &Plane, input: &InputState) {
-    let forward = plane.forward();
-    let up = plane.up();
-    if input.cockpit {
-        camera.position = plane.position + forward * 1.6 + up * 0.4;
-        camera.target = plane.position + forward * 14.0 + up * 0.3;
-        camera.up = up;
+fn apply_brake(plane: &mut Plane, dt: f32) {
+    if plane.on_ground {
+        let ground_speed = Vec2::new(plane.velocity.x, plane.velocity.z);
+        let slowed = ground_speed.length() - WHEEL_BRAKE_DECELERATION * dt;
+        let ground_speed = ground_speed.normalize_or_zero() * slowed.max(0.0);
+        plane.velocity.x = ground_speed.x;
+        plane.velocity.z = ground_speed.y;
     } else {
-        let chase_offset = -forward * 32.0 + up * 14.0 + plane.right() * 3.0;
-        camera.position = plane.position + chase_offset;
-        camera.target = plane.position + forward * 18.0;
-        camera.up = up;
+        let speed = plane.air_velocity.length();
+        plane.velocity -= plane.air_velocity * speed * AIRBRAKE_DRAG * dt;
     }
 }
 
-fn draw_box(center: Vec3, axes: (Vec3, Vec3, Vec3), size: Vec3, color: Color) {
-    let (right, up, forward) = axes;
-    let offset = center - right * (size.x * 0.5) - up * (size.y * 0.5) - forward * (size.z * 0.5);
-    draw_affine_parallelepiped(
-        offset,
-        right * size.x,
-        up * size.y,
-        forward * size.z,
-        None,
-        color,
-    );
+fn pitch_shifted_wav(bytes: &[u8], pitch: f32) -> Option<Vec<u8>> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return None;
+    }
+    let mut shifted = bytes.to_vec();
+    let mut chunk = 12;
+    while chunk + 8 <= bytes.len() {
+        let size = u32::from_le_bytes(bytes[chunk + 4..chunk + 8].try_into().ok()?) as usize;
+        if &bytes[chunk..chunk + 4] == b"fmt " && size >= 16 && chunk + 8 + size <= bytes.len() {
+            let rate_at = chunk + 12;
+            let byte_rate_at = chunk + 16;
+            for field in [rate_at, byte_rate_at] {
+                let value = u32::from_le_bytes(bytes.get(field..field + 4)?.try_into().ok()?);
+                let scaled = (value as f32 * pitch).round() as u32;
+                shifted[field..field + 4].copy_from_slice(&scaled.to_le_bytes());
+            }
+            return Some(shifted);
+        }
+        chunk += 8 + size + size % 2;
+    }
+    None
 }
 
-fn draw_plane_model(plane: &Plane) {
-    let forward = plane.forward();
-    let right = plane.right();
-    let up = plane.up();
+struct EngineAudio {
+    layers: Vec<Sound>,
+}
 
-    draw_box(
-        plane.position + forward * 1.5,
-        (right, up, forward),
-        Vec3::new(2.2, 0.8, 9.0),
-        Color::new(0.86, 0.89, 0.93, 1.0),
-    );
+impl EngineAudio {
+    async fn load() -> Option<Self> {
+        let bytes = match load_file(ENGINE_SOUND_PATH).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                warn!("Engine sound unavailable ({}): {}", ENGINE_SOUND_PATH, err);
+                return None;
+            }
+        };
 
-    draw_box(
-        plane.position,
-        (right, forward, up),
-        Vec3::new(14.0, 0.6, 0.25),
-        Color::new(0.8, 0.82, 0.88, 1.0),
-    );
+        let mut layers = Vec::with_capacity(ENGINE_PITCH_BANDS.len());
+        for pitch in ENGINE_PITCH_BANDS {
+            let Some(shifted) = pitch_shifted_wav(&bytes, pitch) else {
+                warn!("Engine sound {} is not a WAV file", ENGINE_SOUND_PATH);
+                return None;
+            };
+            match load_sound_from_bytes(&shifted).await {
+                Ok(sound) => layers.push(sound),
+                Err(err) => {
+                    warn!(
+                        "Engine sound {} could not be decoded: {}",
+                        ENGINE_SOUND_PATH, err
+                    );
+                    return None;
+                }
+            }
+        }
 
-    draw_box(
-        plane.position + forward * -3.0 + up * -0.2,
-        (right, up, forward),
-        Vec3::new(4.5, 0.4, 3.0),
-        Color::new(0.75, 0.78, 0.82, 1.0),
-    );
+        for layer in &layers {
+            play_sound(
+                layer,
+                PlaySoundParams {
+                    looped: true,
+                    volume: 0.0,
+                },
+            );
+        }
+        Some(Self { layers })
+    }
 
-    draw_box(
-        plane.position + forward * 4.0 + up * 0.6,
-        (right, up, forward),
-        Vec3::new(1.1, 0.9, 1.6),
-        Color::new(0.75, 0.83, 0.95, 1.0),
-    );
+    fn update(&self, plane: &Plane) {
+        let running = plane.fuel > 0.0 && !plane.crashed;
+        let rpm = if running {
+            (plane.engine_rpm / 1.4 * 0.75
+                + plane.velocity.length() / plane.config.max_speed * 0.25)
+                .clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let volume = if running { 0.25 + 0.5 * rpm } else { 0.0 };
+        let band = rpm * (self.layers.len() - 1) as f32;
+        for (i, layer) in self.layers.iter().enumerate() {
+            let weight = (1.0 - (band - i as f32).abs()).max(0.0);
+            set_sound_volume(layer, volume * weight);
+        }
+    }
 }
 
-fn draw_environment(plane: &Plane) {
-    clear_background(Color::from_rgba(36, 115, 195, 255));
-
-    draw_plane(
-        Vec3::new(0.0, 0.0, 0.0),
-        Vec2::new(2500.0, 2500.0),
-        None,
-        Color::new(0.25, 0.47, 0.18, 1.0),
-    );
-
-    draw_grid(
-        80,
-        40.0,
-        Color::new(0.3, 0.35, 0.3, 0.3),
-        Color::new(0.2, 0.25, 0.2, 0.2),
-    );
-
-    for i in -4..=4 {
-        let offset = i as f32 * 320.0;
-        draw_cube(
-            Vec3::new(offset, 300.0, 900.0),
-            Vec3::splat(14.0),
-            None,
-            Color::new(0.9, 0.97, 1.0, 0.55),
-        );
-        draw_cube(
-            Vec3::new(offset * 1.4, 240.0, -1100.0),
-            Vec3::new(22.0, 16.0, 22.0),
-            None,
-            Color::new(0.92, 0.95, 1.0, 0.45),
-        );
+fn beep_wav(frequency: f32) -> Vec<u8> {
+    let sample_rate = 44_100u32;
+    let samples = sample_rate as usize / 2;
+    let mut data = Vec::with_capacity(samples * 2);
+    for i in 0..samples {
+        let t = i as f32 / sample_rate as f32;
+        let value = if i < samples / 2 {
+            (t * frequency * std::f32::consts::TAU).sin() * 0.3
+        } else {
+            0.0
+        };
+        data.extend_from_slice(&((value * i16::MAX as f32) as i16).to_le_bytes());
     }
 
-    draw_plane_model(plane);
+    let mut wav = Vec::with_capacity(44 + data.len());
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes());
+    wav.extend_from_slice(&sample_rate.to_le_bytes());
+    wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    wav.extend_from_slice(&2u16.to_le_bytes());
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    wav.extend_from_slice(&data);
+    wav
 }
 
-fn draw_hud(plane: &Plane, input: &InputState) {
-    set_default_camera();
-    let speed = plane.velocity.length();
-    let altitude = plane.position.y.max(0.0);
-    let throttle = (plane.throttle * 100.0).clamp(0.0, 140.0);
-    let pitch = plane.forward().y.asin().to_degrees();
-    let yaw = plane.forward().x.atan2(-plane.forward().z).to_degrees();
-    let roll = plane.right().y.atan2(plane.up().y).to_degrees();
-    let info = format!(
-        "Hız: {:>6.1} km/h\nİrtifa: {:>6.1} m\nGaz: {:>5.1}%\nPitch: {:>5.1}°\nRoll: {:>5.1}°\nYaw: {:>5.1}°",
-        speed, altitude, throttle, pitch, roll, yaw
-    );
-    draw_text(&info, 24.0, 32.0, 28.0, WHITE);
+struct WarningTone {
+    sound: Sound,
+    volume: f32,
+}
 
-    let controls = "Kontroller: W/S Pitch | A/D Roll | Q/E Yaw | +/- Gaz | Space Fren | C Kokpit";
-    let dims = measure_text(controls, None, 22, 1.0);
-    draw_text(
-        controls,
-        screen_width() * 0.5 - dims.width * 0.5,
-        screen_height() - 24.0,
-        22.0,
-        LIGHTGRAY,
-    );
+impl WarningTone {
+    async fn load(name: &str, frequency: f32, volume: f32) -> Option<Self> {
+        match load_sound_from_bytes(&beep_wav(frequency)).await {
+            Ok(sound) => {
+                play_sound(
+                    &sound,
+                    PlaySoundParams {
+                        looped: true,
+                        volume: 0.0,
+                    },
+                );
+                Some(Self { sound, volume })
+            }
+            Err(err) => {
+                warn!("{} could not be created: {}", name, err);
+                None
+            }
+        }
+    }
 
-    if input.brake {
-        draw_rectangle(
-            screen_width() * 0.5 - 90.0,
-            screen_height() * 0.5 - 28.0,
-            180.0,
-            56.0,
-            Color::new(0.9, 0.15, 0.15, 0.8),
-        );
-        draw_text(
-            "FRENLER",
-            screen_width() * 0.5 - 62.0,
-            screen_height() * 0.5 + 10.0,
-            34.0,
-            WHITE,
-        );
+    fn update(&self, active: bool) {
+        set_sound_volume(&self.sound, if active { self.volume } else { 0.0 });
     }
 }
 
-fn apply_brake(plane: &mut Plane, dt: f32) {
-    let speed = plane.velocity.length();
-    if speed > 1.0 {
-        plane.velocity *= (1.0 - dt * 5.0).clamp(0.0, 0.95);
+fn window_conf() -> Conf {
+    let options = LaunchOptions::parse(std::env::args().skip(1))
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    Conf {
+        window_title: "Rust Flight Simulator".to_string(),
+        window_width: options.width,
+        window_height: options.height,
+        fullscreen: options.fullscreen,
+        sample_count: options.msaa,
+        window_resizable: true,
+        ..Default::default()
     }
 }
 
-#[macroquad::main("Rust Flight Simulator")]
+#[macroquad::main(window_conf)]
 async fn main() {
-    let mut plane = Plane::new();
-    let mut camera = Camera3D {
-        position: Vec3::new(0.0, 120.0, 140.0),
-        target: plane.position,
-        up: Vec3::Y,
-        fovy: 65.0,
-        ..Default::default()
+    let mut launch = match LaunchOptions::parse(std::env::args().skip(1)) {
+        Ok(Some(launch)) => launch,
+        Ok(None) => {
+            println!("{}", USAGE);
+            std::process::exit(0);
+        }
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            std::process::exit(2);
+        }
+    };
+    let bindings = KeyBindings::default();
+    let mut aircraft = launch.aircraft;
+    let mut plane = Plane::new(AIRCRAFT_PRESETS[aircraft], &launch);
+    let mut camera = initial_camera(&plane);
+    let mut view = CameraState::new(launch.camera_shake);
+    let mut world = World::new(launch.wind);
+    let mut replay = Replay::new();
+    let mut clock = SimClock::new();
+    let mut hud = HudSettings::new(
+        launch.units,
+        launch.language,
+        launch.hud_scale,
+        launch.hud_smoothing,
+    );
+    let mut menu = ScenarioMenu::new();
+    let mut in_menu = true;
+    let mut keys = KeyEdges::default();
+    let mut mouse_flight = false;
+    let mut frame_stats = FrameStats::new();
+    let mut flight_log = FlightLog::new();
+    let mut smoothing = ControlSmoothing::default();
+    let mut roll_rate = 0.0;
+    let mut previous_position = plane.position;
+    let mut previous_orientation = plane.orientation;
+    let mut trail: Vec<TrailParticle> = Vec::with_capacity(MAX_TRAIL_PARTICLES);
+    let mut vortices: Vec<VortexParticle> = Vec::with_capacity(MAX_VORTEX_PARTICLES);
+    let mut explosion: Option<Explosion> = None;
+    let mut motion_blur = MotionBlur::new(launch.motion_blur);
+    let engine_audio = EngineAudio::load().await;
+    let stall_horn = WarningTone::load("Stall horn", STALL_HORN_FREQUENCY, STALL_HORN_VOLUME).await;
+    let pull_up_alert =
+        WarningTone::load("Pull-up alert", GPWS_ALERT_FREQUENCY, GPWS_ALERT_VOLUME).await;
+    let mut gamepads = match Gilrs::new() {
+        Ok(gilrs) => Some(gilrs),
+        Err(err) => {
+            warn!("Gamepad support unavailable: {}", err);
+            None
+        }
     };
 
+    prevent_quit();
     loop {
-        let dt = get_frame_time().clamp(1.0 / 200.0, 1.0 / 30.0);
-        let input = InputState::gather();
+        if is_quit_requested() {
+            flight_log.export();
+            break;
+        }
+        keys.poll();
+        if in_menu {
+            if let Some(scenario) = menu.update(&keys) {
+                world.start(scenario);
+                world.restart_coach(launch.takeoff_coach);
+                plane = scenario.start_plane(AIRCRAFT_PRESETS[aircraft], &launch);
+                camera = initial_camera(&plane);
+                previous_position = plane.position;
+                previous_orientation = plane.orientation;
+                trail.clear();
+                vortices.clear();
+                in_menu = false;
+                show_mouse(!mouse_flight);
+            } else {
+                menu.draw(hud.language);
+                next_frame().await;
+                continue;
+            }
+        }
+        frame_stats.record(get_frame_time());
+        let dt = get_frame_time().min(MAX_FRAME_TIME);
+        let mut input = InputState::gather(&bindings, &keys, gamepads.as_mut(), mouse_flight);
+        smoothing.apply(&mut input, dt);
+        if input.open_menu {
+            in_menu = true;
+            show_mouse(true);
+        }
+        if input.toggle_mouse_flight {
+            mouse_flight = !mouse_flight;
+            show_mouse(!mouse_flight);
+        }
+
+        if input.reset || plane.horizontal_distance() > BOUNDARY_HARD_RADIUS {
+            plane = world
+                .scenario
+                .start_plane(AIRCRAFT_PRESETS[aircraft], &launch);
+            camera = initial_camera(&plane);
+            world.route.restart();
+            world.rings.restart();
+            world.reset_targets();
+            world.restart_coach(launch.takeoff_coach);
+            previous_position = plane.position;
+            previous_orientation = plane.orientation;
+        }
+        if plane.crashed && !clock.paused && replay.mode != ReplayMode::Playing {
+            plane.respawn_timer -= dt;
+            if plane.respawn_timer <= 0.0 {
+                plane = world
+                    .scenario
+                    .start_plane(AIRCRAFT_PRESETS[aircraft], &launch);
+                if world.route.active > 0 {
+                    let from = world.route.waypoints[world.route.active - 1];
+                    plane.place_at(from, world.route.target() - from);
+                }
+                world.restart_coach(launch.takeoff_coach);
+                camera = initial_camera(&plane);
+                previous_position = plane.position;
+                previous_orientation = plane.orientation;
+            }
+        }
+        if input.cycle_aircraft {
+            aircraft = (aircraft + 1) % AIRCRAFT_PRESETS.len();
+            plane.config = AIRCRAFT_PRESETS[aircraft];
+        }
+        if input.cycle_view {
+            view.cycle();
+        }
+        if input.toggle_camera_shake {
+            view.shake = !view.shake;
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Kamera sarsıntısı", "Camera shake"),
+                hud.language.on_off(view.shake)
+            ));
+        }
+        if input.toggle_gear {
+            plane.gear_down = !plane.gear_down;
+        }
+        if input.flaps_step != 0.0 {
+            plane.step_flaps(input.flaps_step);
+        }
+        if input.toggle_autopilot {
+            plane.toggle_autopilot();
+        }
+        if input.toggle_auto_throttle {
+            plane.toggle_auto_throttle();
+        }
+        if input.toggle_return_to_base {
+            plane.toggle_return_to_base();
+        }
+        if input.toggle_turn_assist {
+            plane.turn_assist = !plane.turn_assist;
+        }
+        if input.toggle_turbulence {
+            world.weather.turbulence = !world.weather.turbulence;
+        }
+        if input.cycle_gravity {
+            world.gravity = world.gravity.next();
+        }
+        if input.toggle_obstacles {
+            world.solid_clouds = !world.solid_clouds;
+        }
+        if input.toggle_horizon_clouds {
+            world.horizon_layer = !world.horizon_layer;
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Ufuk bulutları", "Horizon clouds"),
+                hud.language.on_off(world.horizon_layer)
+            ));
+        }
+        if input.toggle_tapes {
+            hud.tapes = !hud.tapes;
+        }
+        if input.toggle_frame_stats {
+            hud.frame_stats = !hud.frame_stats;
+        }
+        if input.toggle_force_vectors {
+            hud.force_vectors = !hud.force_vectors;
+        }
+        if input.toggle_debug_state {
+            hud.debug_state = !hud.debug_state;
+        }
+        if input.toggle_settings {
+            hud.panel = !hud.panel;
+        }
+        if input.toggle_motion_blur {
+            motion_blur.enabled = !motion_blur.enabled;
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Hareket bulanıklığı", "Motion blur"),
+                hud.language.on_off(motion_blur.enabled)
+            ));
+        }
+        if input.cycle_physics {
+            launch.physics = launch.physics.next();
+            plane.physics = launch.physics;
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Fizik", "Physics"),
+                launch.physics.label(hud.language)
+            ));
+        }
+        if input.expo_step != 0.0 {
+            launch.expo = (launch.expo + input.expo_step * EXPO_STEP).clamp(0.0, MAX_EXPO);
+            plane.expo = launch.expo;
+            hud.notify(format!("Expo: {:.1}", launch.expo));
+        }
+        if input.toggle_pitch_inversion {
+            launch.invert_pitch = !launch.invert_pitch;
+            plane.pitch_inverted = launch.invert_pitch;
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Ters pitch", "Inverted pitch"),
+                hud.language.on_off(launch.invert_pitch)
+            ));
+        }
+        if input.hud_scale_step != 0 {
+            hud.step_scale(input.hud_scale_step);
+        }
+        if input.cycle_units {
+            hud.units = hud.units.next();
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Birimler", "Units"),
+                hud.units.label(hud.language)
+            ));
+        }
+        if input.cycle_language {
+            hud.language = hud.language.next();
+            hud.notify(format!(
+                "{}: {}",
+                hud.language.text("Dil", "Language"),
+                hud.language.label()
+            ));
+        }
+        hud.notice_timer = (hud.notice_timer - dt).max(0.0);
+        if input.toggle_pause {
+            clock.paused = !clock.paused;
+        }
+        if input.time_scale_step != 0 {
+            clock.step_time_scale(input.time_scale_step);
+        }
+        if input.toggle_recording {
+            replay.toggle_recording();
+        }
+        if input.toggle_playback {
+            replay.toggle_playback();
+        }
+        if input.toggle_unlimited_fuel {
+            plane.unlimited_fuel = !plane.unlimited_fuel;
+        }
+        if world.scenario == Scenario::TargetPractice {
+            input.brake = false;
+            if input.fire && !plane.crashed && replay.mode != ReplayMode::Playing {
+                world.fire(&plane);
+            }
+        }
 
-        plane.update(dt, &input);
-        if input.brake {
-            apply_brake(&mut plane, dt);
+        let sim_dt = dt * clock.time_scale();
+        if !clock.paused {
+            clock.accumulator += sim_dt;
+            clock.elapsed += sim_dt;
+            while clock.accumulator >= PHYSICS_DT {
+                previous_position = plane.position;
+                previous_orientation = plane.orientation;
+                let roll_before = plane.roll();
+                let airborne = !plane.on_ground;
+                if replay.mode == ReplayMode::Playing {
+                    replay.play(&mut plane, PHYSICS_DT);
+                } else if !plane.crashed {
+                    world.weather.advance(PHYSICS_DT);
+                    plane.update(PHYSICS_DT, &input, &world.weather, world.gravity.vector());
+                    if input.brake {
+                        apply_brake(&mut plane, PHYSICS_DT);
+                    }
+                    replay.record(&plane, PHYSICS_DT);
+                }
+                roll_rate = wrap_degrees(plane.roll() - roll_before) / PHYSICS_DT;
+                for traffic in &mut world.traffic {
+                    traffic.step(PHYSICS_DT, &world.weather, world.gravity.vector());
+                }
+                let course_time = world.route.update(plane.position, clock.elapsed);
+                let ring_time = if world.scenario == Scenario::RingRace {
+                    world
+                        .rings
+                        .update(previous_position, plane.position, clock.elapsed)
+                } else {
+                    None
+                };
+                if replay.mode != ReplayMode::Playing {
+                    if let Some(time) = course_time {
+                        world.scores.record_course(time);
+                    }
+                    if let Some(time) = ring_time {
+                        world.scores.record_rings(time);
+                    }
+                    world
+                        .aerobatics
+                        .update(previous_orientation, &plane, PHYSICS_DT);
+                    if airborne
+                        && plane.on_ground
+                        && let Some(score) = plane.landing_score
+                    {
+                        world.scores.record_landing(score);
+                    }
+                }
+                world.update_projectiles(PHYSICS_DT);
+                if world.cloud_collision(plane.position) {
+                    plane.crashed = true;
+                }
+                clock.accumulator -= PHYSICS_DT;
+            }
         }
 
-        update_camera(&mut camera, &plane, &input);
+        if let Some(coach) = &mut world.coach {
+            coach.update(&plane, sim_dt);
+        }
+
+        let alpha = clock.accumulator / PHYSICS_DT;
+        let mut rendered = plane.clone();
+        rendered.position = previous_position.lerp(plane.position, alpha);
+        rendered.orientation = previous_orientation.slerp(plane.orientation, alpha);
+        let traffic: Vec<Plane> = world
+            .traffic
+            .iter()
+            .map(|traffic| traffic.rendered(alpha))
+            .collect();
+
+        if !clock.paused {
+            flight_log.record(clock.elapsed, &plane);
+            update_trail(&mut trail, &rendered, roll_rate, sim_dt);
+            motion_blur.record(&rendered);
+            update_vortices(&mut vortices, &rendered, sim_dt);
+            if !plane.crashed {
+                explosion = None;
+            } else if explosion.is_none() {
+                explosion = Some(Explosion::new(&rendered));
+            }
+            if let Some(explosion) = &mut explosion {
+                explosion.update(world.gravity.vector(), sim_dt);
+            }
+            world.time_of_day.advance(sim_dt, input.time_scrub);
+        }
+
+        if let Some(engine_audio) = &engine_audio {
+            engine_audio.update(&rendered);
+        }
+        if let Some(stall_horn) = &stall_horn {
+            stall_horn.update(rendered.stall_warning());
+        }
+        if let Some(pull_up_alert) = &pull_up_alert {
+            pull_up_alert.update(rendered.pull_up_warning());
+        }
+
+        update_camera(
+            &mut camera,
+            &mut view,
+            &rendered,
+            &input,
+            &world.weather,
+            clock.paused,
+            dt,
+        );
+        world.terrain.apply_lighting(
+            world.time_of_day.ambient(),
+            camera.position,
+            world.time_of_day.horizon_color(),
+        );
+        draw_sky(&camera, &world.time_of_day);
         set_camera(&camera);
-        draw_environment(&plane);
-        draw_hud(&plane, &input);
+        let ghosts = motion_blur.ghosts(&rendered);
+        let effects = SceneEffects {
+            ghosts: &ghosts,
+            trail: &trail,
+            vortices: &vortices,
+            explosion: explosion.as_ref(),
+        };
+        draw_environment(&rendered, &traffic, &effects, &world, camera.position);
+        if hud.force_vectors {
+            draw_force_vectors(&rendered, hud.language);
+        }
+        hud.readouts.track(&plane, dt, hud.smoothing);
+        draw_hud(&rendered, &camera, &input, &world, &replay, &hud, &clock);
+        if hud.frame_stats {
+            draw_frame_stats(&frame_stats);
+        }
+        if input.screenshot {
+            save_screenshot();
+        }
+        if input.export_log {
+            flight_log.export();
+        }
 
         next_frame().await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level_plane(roll: f32) -> Plane {
+        let mut plane = Plane::new(AIRCRAFT_PRESETS[0], &LaunchOptions::default());
+        plane.position.y = 1000.0;
+        plane.orientation = Quat::from_rotation_z(roll.to_radians());
+        plane
+    }
+
+    fn calm_weather() -> Weather {
+        let mut weather = Weather::new(Vec3::ZERO);
+        weather.turbulence = false;
+        weather
+    }
+
+    fn pitch_about_right(plane: &mut Plane, input: &InputState) -> f32 {
+        let (forward, right) = (plane.forward(), plane.right());
+        plane.update(PHYSICS_DT, input, &calm_weather(), GRAVITY);
+        Vec3::dot(forward.cross(plane.forward()), right)
+    }
+
+    #[test]
+    fn pitch_input_rotates_about_body_right_when_inverted() {
+        let input = InputState {
+            pitch_up: 1.0,
+            ..Default::default()
+        };
+        let upright = pitch_about_right(&mut level_plane(0.0), &input);
+        let inverted = pitch_about_right(&mut level_plane(180.0), &input);
+        assert!(upright > 0.0);
+        assert!(inverted > 0.0);
+        assert!((upright - inverted).abs() < upright * 0.05);
+
+        let mut plane = level_plane(180.0);
+        plane.update(PHYSICS_DT, &input, &calm_weather(), GRAVITY);
+        assert!(plane.forward().y < 0.0);
+    }
+
+    #[test]
+    fn roll_right_drops_right_wing() {
+        let input = InputState {
+            roll_right: 1.0,
+            ..Default::default()
+        };
+        for roll in [0.0, 180.0] {
+            let mut plane = level_plane(roll);
+            let before = plane.up();
+            plane.update(PHYSICS_DT, &input, &calm_weather(), GRAVITY);
+            assert!(Vec3::dot(plane.right(), before) < 0.0);
+        }
+        let mut plane = level_plane(0.0);
+        plane.update(PHYSICS_DT, &input, &calm_weather(), GRAVITY);
+        assert!(plane.right().y < 0.0);
+    }
+
+    #[test]
+    fn key_edges_track_press_hold_release() {
+        let mut keys = KeyEdges::default();
+        let frames = [
+            (true, true, true),
+            (true, false, true),
+            (true, false, true),
+            (false, false, false),
+            (true, true, true),
+        ];
+        for (down, pressed, held) in frames {
+            let state = if down {
+                HashSet::from([KeyCode::G])
+            } else {
+                HashSet::new()
+            };
+            keys.advance(state);
+            assert_eq!(keys.just_pressed(KeyCode::G), pressed);
+            assert_eq!(keys.held(KeyCode::G), held);
+            assert!(!keys.just_pressed(KeyCode::H));
+        }
+    }
+
+    #[test]
+    fn pitch_shifted_wav_rejects_truncated_fmt_chunk() {
+        let wav = beep_wav(440.0);
+        let shifted = pitch_shifted_wav(&wav, 2.0).expect("valid wav");
+        assert_eq!(shifted[24..28], 88_200u32.to_le_bytes());
+        for len in 12..36 {
+            assert!(pitch_shifted_wav(&wav[..len], 2.0).is_none());
+        }
+    }
+
+    #[test]
+    fn language_option_selects_hud_labels() {
+        let parse = |args: &[&str]| {
+            LaunchOptions::parse(args.iter().map(|arg| arg.to_string()))
+                .unwrap()
+                .unwrap()
+        };
+        assert!(parse(&[]).language == Language::Turkish);
+        let english = parse(&["--language", "en"]).language;
+        assert!(english == Language::English);
+        assert_eq!(Scenario::FreeFlight.label(english), "Free Flight");
+        assert_eq!(english.on_off(true), "on");
+        assert!(LaunchOptions::parse(["--language", "de"].map(String::from).into_iter()).is_err());
+    }
+
+    #[test]
+    fn wind_option_parses_components() {
+        let parse =
+            |value: &str| LaunchOptions::parse(["--wind", value].map(String::from).into_iter());
+        assert_eq!(
+            parse("-3, 0, 2.5").unwrap().unwrap().wind,
+            Vec3::new(-3.0, 0.0, 2.5)
+        );
+        assert_eq!(
+            parse("100,0,0").unwrap().unwrap().wind,
+            Vec3::new(MAX_WIND, 0.0, 0.0)
+        );
+        assert!(parse("1,2").is_err());
+        assert!(parse("1,x,2").is_err());
+    }
+
+    #[test]
+    fn rolling_resistance_scales_with_gravity() {
+        let rolled = |gravity: Vec3| {
+            let mut plane = Plane::on_runway(AIRCRAFT_PRESETS[0], &LaunchOptions::default());
+            plane.velocity = plane.forward() * 10.0;
+            plane.roll_on_wheels(1.0, gravity);
+            10.0 - plane.velocity.length()
+        };
+        let earth = rolled(GRAVITY);
+        let moon = rolled(MOON_GRAVITY);
+        assert!(earth > 0.0);
+        assert!((moon / earth - MOON_GRAVITY.y / GRAVITY.y).abs() < 1e-3);
+        assert_eq!(rolled(Vec3::ZERO), 0.0);
+    }
+
+    #[test]
+    fn energy_height_is_constant_in_a_ballistic_climb() {
+        for gravity in [GRAVITY, MOON_GRAVITY] {
+            let mut plane = level_plane(0.0);
+            let (start, climb) = (Vec3::new(0.0, 100.0, 0.0), Vec3::new(0.0, 40.0, -30.0));
+            plane.position = start;
+            plane.velocity = climb;
+            let initial = plane.energy_height(gravity).unwrap();
+            for step in 1..=10 {
+                let t = step as f32 * 0.5;
+                plane.position = start + climb * t + gravity * (0.5 * t * t);
+                plane.velocity = climb + gravity * t;
+                let energy = plane.energy_height(gravity).unwrap();
+                assert!((energy - initial).abs() < 0.01 * initial);
+            }
+        }
+        assert!(level_plane(0.0).energy_height(Vec3::ZERO).is_none());
+    }
+
+    #[test]
+    fn wrap_entries_keeps_lines_within_width() {
+        let measure = |line: &str| line.chars().count() as f32;
+        let lines = wrap_entries(CONTROLS_HINT_EN, 60.0, measure);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| measure(line) <= 60.0));
+        assert_eq!(lines.join(" | "), CONTROLS_HINT_EN);
+    }
+}